@@ -1,41 +1,536 @@
-use crate::boxscore::BoxScore;
-use crate::pbp::{Play, PlayByPlay};
-use crate::utils::{StatefulList, TabTeam, TabsState};
-use reqwest::blocking::Client;
-
-pub struct App<'a> {
-    pub title: &'a str,
-    pub tabs: TabsState<'a>,
-    pub boxscore: BoxScore<'a>,
+use crate::columns::{self, Column};
+use crate::config::Config;
+use crate::keys::{Action, KeyMap};
+use crate::network::{Json, Request, Resource, Update};
+use crate::theme::Theme;
+use crossterm::event::{MouseButton, MouseEvent, MouseEventKind};
+use nil::boxscore::BoxScore;
+use nil::bracket::{self, Series};
+use nil::lineups::Lineups;
+use nil::pbp::{self, GameFlow, Period, Play};
+use nil::periods::{Segment, SegmentStats};
+use nil::profile::SeasonAverages;
+use nil::schedule::{Game, Schedule, Scoreboard};
+use nil::standings::{self, Standings, TeamGame};
+use nil::stats::Stats;
+use nil::teams::TEAMS;
+use nil::utils::{Player, TimeZone};
+use nil::Error;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc::UnboundedSender;
+use tui::layout::Rect;
+use tui::widgets::{ListState, TableState};
+
+const SPINNER: [&str; 4] = ["|", "/", "-", "\\"];
+const BRAILLE_SPINNER: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+
+#[derive(Clone)]
+pub enum Status {
+    Idle,
+    Loading,
+    Loaded,
+    Failed(String),
+}
+
+pub struct App {
+    pub title: &'static str,
+    pub tabs: TabsState,
+    pub schedule: Option<Schedule<'static>>,
+    /// Games of `date` from the scoreboard feed, fresher than the schedule.
+    pub scores: Option<Scoreboard<'static>>,
+    pub boxscore: Option<BoxScore<'static>>,
+    pub standings: Option<Standings>,
+    /// Whether standings are grouped by division instead of conference.
+    pub by_division: bool,
+    /// Whether the key bindings popup is open.
+    pub show_help: bool,
+    /// Whether the detail popup of the selected player is open.
+    pub show_player: bool,
+    /// Season averages by person id.
+    pub profiles: HashMap<String, SeasonAverages>,
+    /// Time of the last failed profile fetch by person id, retried after
+    /// `PROFILE_RETRY`.
+    pub profile_failures: HashMap<String, Instant>,
+    /// Selected player of the boxscore table.
+    pub player: Option<usize>,
+    pub player_offset: usize,
+    /// Kept across frames for the table to scroll like `player_offset`.
+    pub players: TableState,
+    /// Columns of the boxscore table besides the player name.
+    pub box_columns: Vec<&'static Column>,
+    /// First stat column shown when the boxscore is wider than the screen.
+    pub box_column: usize,
+    /// Areas of the last drawn frame, for mouse hit-testing.
+    pub areas: Areas,
     pub enhanced_graphics: bool,
-    pub plays: StatefulList<Play<'a>>,
-    client: Client,
+    pub plays: StatefulList<Play<'static>>,
+    /// Lead changes, ties and runs of the play by play.
+    pub flow: GameFlow,
+    /// Lineups and on/off splits of the current game.
+    pub lineups: Lineups<'static>,
+    /// Period of the shots of the shot chart, every period when `None`.
+    pub shot_period: Option<Period>,
+    /// Index in `shooters` of the player of the shot chart, the whole team
+    /// when `None`.
+    pub shooter: Option<usize>,
+    /// Part of the game of the boxscore tab, the whole game from the
+    /// boxscore feed when `None`.
+    pub box_segment: Option<Segment>,
+    /// Stats of `box_segment` counted from the play by play.
+    pub segment_stats: Option<SegmentStats<'static>>,
+    /// Players whose points counted from the whole play by play differ from
+    /// the boxscore feed.
+    pub segment_mismatches: usize,
+    pub date: String,
+    pub timezone: TimeZone,
+    /// Team ids of the favorite teams.
+    pub favorites: Vec<&'static str>,
+    pub keys: KeyMap,
+    pub theme: Theme,
+    pub game_id: Option<String>,
+    /// Date of the current game, which can differ from `date` when it was
+    /// opened from a team's schedule.
+    pub game_date: String,
+    /// Selected game of the scoreboard.
+    pub scoreboard: usize,
+    /// Selected series of the bracket.
+    pub bracket: usize,
+    /// Index in `TEAMS` of the team shown in the team tab.
+    pub team: usize,
+    /// Selected game of the team tab, defaults to the last finished game.
+    team_game: Option<usize>,
+    /// Last time the game data was successfully updated.
+    pub last_update: Option<Instant>,
+    statuses: [Status; 5],
+    /// Number of the last request of each resource, older responses are
+    /// dropped.
+    requests: [u64; 5],
+    seq: u64,
+    spinner: usize,
+    network: UnboundedSender<Request>,
+    refresh_rate: Duration,
+    last_refresh: Instant,
+    /// Json of each resource, sent with its requests so that an unchanged
+    /// feed is not parsed again.
+    json: [Option<Json>; 5],
 }
 
-impl<'a> App<'a> {
+impl App {
     pub fn new(
-        title: &'a str,
-        enhanced_graphics: bool,
-        boxscore: BoxScore<'a>,
-        playbyplay: PlayByPlay<'a>,
-        client: Client,
-    ) -> App<'a> {
-        App {
+        title: &'static str,
+        config: &Config,
+        network: UnboundedSender<Request>,
+    ) -> Result<App, Error> {
+        let date = config.date();
+        let mut app = App {
             title,
-            tabs: TabsState::new(["Game", "Boxscore"]),
-            boxscore,
-            enhanced_graphics,
-            plays: StatefulList::with_items(playbyplay.plays),
-            client,
+            tabs: TabsState::new(),
+            schedule: None,
+            scores: None,
+            boxscore: None,
+            standings: None,
+            by_division: false,
+            show_help: false,
+            show_player: false,
+            profiles: HashMap::new(),
+            profile_failures: HashMap::new(),
+            player: None,
+            player_offset: 0,
+            players: TableState::default(),
+            box_columns: columns::from_ids(&config.boxscore_columns)?,
+            box_column: 0,
+            areas: Areas::default(),
+            enhanced_graphics: config.enhanced_graphics,
+            plays: StatefulList::with_items(Vec::new()),
+            flow: GameFlow::default(),
+            lineups: Lineups::default(),
+            shot_period: None,
+            shooter: None,
+            box_segment: None,
+            segment_stats: None,
+            segment_mismatches: 0,
+            game_date: date.clone(),
+            date,
+            timezone: config.timezone.parse()?,
+            favorites: config.favorites()?,
+            keys: KeyMap::new(&config.keys)?,
+            theme: Theme::new(config)?,
+            game_id: None,
+            scoreboard: 0,
+            bracket: 0,
+            team: 0,
+            team_game: None,
+            last_update: None,
+            statuses: [
+                Status::Idle,
+                Status::Idle,
+                Status::Idle,
+                Status::Idle,
+                Status::Idle,
+            ],
+            requests: [0; 5],
+            seq: 0,
+            spinner: 0,
+            network,
+            refresh_rate: Duration::from_secs(config.refresh),
+            last_refresh: Instant::now(),
+            json: [None, None, None, None, None],
+        };
+        app.refresh();
+        Ok(app)
+    }
+
+    /// Whether the schedule is loaded and has no game on `date`.
+    pub fn no_games(&self) -> bool {
+        self.schedule.is_some() && self.game_id.is_none()
+    }
+
+    pub fn status(&self, resource: Resource) -> &Status {
+        &self.statuses[resource as usize]
+    }
+
+    /// Failed refresh of data that is still shown, if any.
+    pub fn stale_error(&self) -> Option<&str> {
+        Resource::ALL.iter().find_map(|&r| match self.status(r) {
+            Status::Failed(e) if self.is_loaded(r) => Some(e.as_str()),
+            _ => None,
+        })
+    }
+
+    /// Failed fetch that left the current game without a boxscore, if any.
+    pub fn game_error(&self) -> Option<&str> {
+        if self.boxscore.is_some() {
+            return None;
+        }
+        [Resource::BoxScore, Resource::Roster]
+            .iter()
+            .find_map(|&r| match self.status(r) {
+                Status::Failed(e) => Some(e.as_str()),
+                _ => None,
+            })
+    }
+
+    fn is_loaded(&self, resource: Resource) -> bool {
+        match resource {
+            Resource::Schedule => self.schedule.is_some(),
+            Resource::Scoreboard => self.scores.is_some(),
+            _ => self.last_update.is_some(),
+        }
+    }
+
+    pub fn spinner(&self) -> &'static str {
+        if self.enhanced_graphics {
+            BRAILLE_SPINNER[self.spinner % BRAILLE_SPINNER.len()]
+        } else {
+            SPINNER[self.spinner % SPINNER.len()]
+        }
+    }
+
+    /// Games of `date`, the ones involving a favorite team first. They are
+    /// taken from the scoreboard once loaded, which is kept up to date.
+    pub fn date_games(&self) -> Vec<&Game<'static>> {
+        match (&self.scores, &self.schedule) {
+            (Some(scores), _) => self.sort_favorites(scores.games().iter().collect()),
+            (None, Some(schedule)) => self.sort_favorites(schedule.get_date_games(&self.date)),
+            (None, None) => Vec::new(),
+        }
+    }
+
+    pub fn is_favorite(&self, team_id: &str) -> bool {
+        self.favorites.contains(&team_id)
+    }
+
+    fn sort_favorites<'b>(&self, mut games: Vec<&'b Game<'static>>) -> Vec<&'b Game<'static>> {
+        games.sort_by_key(|x| {
+            !(self.is_favorite(x.h_team().team_id()) || self.is_favorite(x.v_team().team_id()))
+        });
+        games
+    }
+
+    /// Schedule entry of the current game.
+    pub fn current_game(&self) -> Option<&Game<'static>> {
+        let game_id = self.game_id.as_ref()?;
+        self.schedule
+            .as_ref()?
+            .games()
+            .iter()
+            .find(|x| x.game_id() == game_id)
+    }
+
+    /// Start time of `game` in the configured timezone, followed by the
+    /// zone abbreviation for named timezones. Chrono only knows the offset
+    /// of the local timezone, which is left out.
+    pub fn start_time(&self, game: &Game) -> String {
+        let format = match self.timezone {
+            TimeZone::Local => "%-I:%M %p",
+            TimeZone::Named(_) => "%-I:%M %p %Z",
+        };
+        match game.start_time() {
+            Some(_) if game.is_start_time_tbd() => "TBD".to_string(),
+            Some(time) => self.timezone.format(&time, format),
+            None => game.start_time_eastern().to_string(),
+        }
+    }
+
+    /// Playoff series of the schedule.
+    pub fn series(&self) -> Vec<Series<'_, 'static>> {
+        match &self.schedule {
+            Some(schedule) => bracket::series(schedule),
+            None => Vec::new(),
+        }
+    }
+
+    /// Schedule of the team shown in the team tab.
+    pub fn team_schedule(&self) -> Vec<TeamGame<'_, 'static>> {
+        match &self.schedule {
+            Some(schedule) => standings::team_schedule(schedule, TEAMS[self.team].team_id),
+            None => Vec::new(),
+        }
+    }
+
+    pub fn team_game(&self) -> usize {
+        match self.team_game {
+            Some(i) => i,
+            None => self
+                .team_schedule()
+                .iter()
+                .rposition(|x| x.game.status_num() != 1)
+                .unwrap_or(0),
+        }
+    }
+
+    /// Team still scoring unanswered points at the end of the play by play,
+    /// as whether it is the home team and its points.
+    pub fn current_run(&self) -> Option<(bool, u8)> {
+        pbp::runs(&self.plays.items, 1)
+            .last()
+            .map(|x| (x.home, x.points))
+    }
+
+    /// Tri code of the team that most likely has the ball, guessed from the
+    /// last play: made shots, turnovers and fouls give it to the opponent.
+    pub fn possession(&self) -> Option<&str> {
+        let boxscore = self.boxscore.as_ref()?;
+        let plays = &self.plays.items;
+        let last = plays.last()?;
+        let team = last.team?;
+        let previous = plays.len().checked_sub(2).and_then(|i| plays.get(i));
+        let description = last.description.to_ascii_lowercase();
+        let to_opponent = last.is_scoring(previous)
+            || description.contains("turnover")
+            || description.contains("foul");
+        let (h, v) = (boxscore.h_team.tri_code, boxscore.v_team.tri_code);
+        match (team == h, to_opponent) {
+            (true, false) | (false, true) => Some(h),
+            (true, true) | (false, false) => Some(v),
+        }
+        .filter(|_| team == h || team == v)
+    }
+
+    /// Players of the team shown in the boxscore tab.
+    pub fn team_players(&self) -> Vec<&Player<'static>> {
+        let team_id = self.get_current_team();
+        match &self.boxscore {
+            Some(boxscore) => boxscore
+                .players
+                .iter()
+                .filter(|x| x.team_id == Some(team_id))
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Players of the team shown in the boxscore tab who attempted a shot,
+    /// in the boxscore order.
+    pub fn shooters(&self) -> Vec<&'static str> {
+        let tri_code = self.current_tri_code();
+        self.team_players()
+            .iter()
+            .map(|x| x.person_id())
+            .filter(|&id| {
+                self.plays
+                    .items
+                    .iter()
+                    .any(|x| x.shot.is_some() && x.team == tri_code && x.person_id == Some(id))
+            })
+            .collect()
+    }
+
+    /// Plays with a shot of the shot chart, filtered by team, period and
+    /// player.
+    pub fn shots(&self) -> Vec<&Play<'static>> {
+        let tri_code = self.current_tri_code();
+        let shooter = self.shooter.and_then(|i| self.shooters().get(i).copied());
+        self.plays
+            .items
+            .iter()
+            .filter(|x| x.shot.is_some() && x.team == tri_code)
+            .filter(|x| self.shot_period.is_none_or(|p| Period::new(x.period) == p))
+            .filter(|x| shooter.is_none_or(|id| x.person_id == Some(id)))
+            .collect()
+    }
+
+    fn current_tri_code(&self) -> Option<&'static str> {
+        match (&self.boxscore, self.tabs.team) {
+            (Some(b), TabTeam::Home) => Some(b.h_team.tri_code),
+            (Some(b), TabTeam::Visitor) => Some(b.v_team.tri_code),
+            (None, _) => None,
+        }
+    }
+
+    /// Last name of the player `person_id` of the current game.
+    pub fn last_name(&self, person_id: &str) -> &'static str {
+        self.boxscore
+            .as_ref()
+            .and_then(|x| x.players.iter().find(|x| x.person_id() == person_id))
+            .map_or("?", |x| x.last_name)
+    }
+
+    /// Totals of the team shown in the boxscore tab.
+    pub fn team_totals(&self) -> Stats {
+        if let Some(stats) = &self.segment_stats {
+            return stats.team(self.tabs.team == TabTeam::Home);
+        }
+        self.boxscore
+            .as_ref()
+            .and_then(|x| x.totals(self.get_current_team()))
+            .map(Stats::from)
+            .unwrap_or_default()
+    }
+
+    /// Stats of a player in the part of the game shown in the boxscore tab.
+    pub fn player_stats(&self, player: &Player) -> Stats {
+        match &self.segment_stats {
+            Some(stats) => stats.player(player.person_id()),
+            None => Stats::from(player),
+        }
+    }
+
+    /// The selected player of the boxscore table.
+    pub fn selected_player(&self) -> Option<&Player<'static>> {
+        self.player
+            .and_then(|i| self.team_players().get(i).copied())
+    }
+
+    /// Opens the detail popup of the selected player and fetches their
+    /// season averages.
+    fn open_player(&mut self) {
+        let person_id = match self.selected_player() {
+            Some(player) => player.person_id().to_string(),
+            None => return,
+        };
+        self.show_player = true;
+        let failed = self
+            .profile_failures
+            .get(&person_id)
+            .is_some_and(|x| x.elapsed() < PROFILE_RETRY);
+        if !self.profiles.contains_key(&person_id) && !failed {
+            self.profile_failures.remove(&person_id);
+            self.request(Request::Profile { person_id }, false);
+        }
+    }
+
+    fn select_player(&mut self, player: Option<usize>) {
+        self.player = player;
+        if player.is_none() {
+            self.player_offset = 0;
         }
     }
 
     pub fn on_up(&mut self) {
-        self.plays.previous();
+        match self.tabs.tab {
+            Tab::Game => self.plays.previous(),
+            Tab::Boxscore => {
+                self.select_player(Some(self.player.map_or(0, |x| x.saturating_sub(1))))
+            }
+            Tab::Scoreboard => self.scoreboard = self.scoreboard.saturating_sub(1),
+            Tab::Bracket => self.bracket = self.bracket.saturating_sub(1),
+            Tab::Team => self.team_game = Some(self.team_game().saturating_sub(1)),
+            Tab::Shots => self.shooter = self.shooter.and_then(|x| x.checked_sub(1)),
+            Tab::Standings | Tab::Compare | Tab::Lineups | Tab::Rotation => {}
+        }
     }
 
     pub fn on_down(&mut self) {
-        self.plays.next();
+        match self.tabs.tab {
+            Tab::Game => self.plays.next(),
+            Tab::Boxscore => {
+                let len = self.team_players().len();
+                match self.player {
+                    Some(i) if i + 1 < len => self.player = Some(i + 1),
+                    None if len > 0 => self.player = Some(0),
+                    _ => {}
+                }
+            }
+            Tab::Scoreboard => {
+                if self.scoreboard + 1 < self.date_games().len() {
+                    self.scoreboard += 1;
+                }
+            }
+            Tab::Bracket => {
+                if self.bracket + 1 < self.series().len() {
+                    self.bracket += 1;
+                }
+            }
+            Tab::Team => {
+                let i = self.team_game();
+                if i + 1 < self.team_schedule().len() {
+                    self.team_game = Some(i + 1);
+                }
+            }
+            Tab::Shots => {
+                let len = self.shooters().len();
+                match self.shooter {
+                    Some(i) if i + 1 < len => self.shooter = Some(i + 1),
+                    None if len > 0 => self.shooter = Some(0),
+                    _ => {}
+                }
+            }
+            Tab::Standings | Tab::Compare | Tab::Lineups | Tab::Rotation => {}
+        }
+    }
+
+    pub fn on_enter(&mut self) {
+        let game = match self.tabs.tab {
+            Tab::Scoreboard => self
+                .date_games()
+                .get(self.scoreboard)
+                .map(|x| (x.start_date_eastern(), x.game_id())),
+            Tab::Team => self
+                .team_schedule()
+                .get(self.team_game())
+                .filter(|x| x.game.status_num() != 1)
+                .map(|x| (x.game.start_date_eastern(), x.game.game_id())),
+            Tab::Boxscore => return self.open_player(),
+            _ => None,
+        };
+        if let Some((date, game_id)) = game {
+            self.open_game(date.to_string(), game_id.to_string());
+        }
+    }
+
+    /// Switches to the game `game_id` of `date` and shows its play by play.
+    fn open_game(&mut self, date: String, game_id: String) {
+        if self.game_id.as_ref() != Some(&game_id) {
+            self.game_id = Some(game_id);
+            self.game_date = date;
+            self.select_player(None);
+            self.boxscore = None;
+            self.plays = StatefulList::with_items(Vec::new());
+            self.flow = GameFlow::default();
+            self.lineups = Lineups::default();
+            self.shot_period = None;
+            self.shooter = None;
+            self.box_segment = None;
+            self.segment_stats = None;
+            self.segment_mismatches = 0;
+            self.last_update = None;
+            self.set_json(Resource::BoxScore, None);
+            self.set_json(Resource::PlayByPlay, None);
+            self.request_game(false);
+        }
+        self.tabs.tab = Tab::Game;
     }
 
     pub fn on_right(&mut self) {
@@ -43,8 +538,32 @@ impl<'a> App<'a> {
     }
 
     pub fn next_team(&mut self) {
-        if self.tabs.index == 1 {
-            self.tabs.next_team();
+        match self.tabs.tab {
+            tab if tab.has_team_tabs() => {
+                self.tabs.next_team();
+                self.select_player(None);
+                self.shooter = None;
+            }
+            Tab::Team => {
+                self.team = (self.team + 1) % TEAMS.len();
+                self.team_game = None;
+            }
+            _ => {}
+        }
+    }
+
+    pub fn previous_team(&mut self) {
+        match self.tabs.tab {
+            tab if tab.has_team_tabs() => {
+                self.tabs.next_team();
+                self.select_player(None);
+                self.shooter = None;
+            }
+            Tab::Team => {
+                self.team = (self.team + TEAMS.len() - 1) % TEAMS.len();
+                self.team_game = None;
+            }
+            _ => {}
         }
     }
 
@@ -52,20 +571,510 @@ impl<'a> App<'a> {
         self.tabs.previous();
     }
 
-    pub fn on_key(&mut self, c: char) {
-        match c {
+    /// Runs `action`, quitting is left to the caller.
+    pub fn on_action(&mut self, action: Action) {
+        match action {
+            Action::NextTab => self.on_right(),
+            Action::PrevTab => self.on_left(),
+            Action::ScrollUp => self.on_up(),
+            Action::ScrollDown => self.on_down(),
+            Action::ScrollLeft if self.tabs.tab == Tab::Boxscore => {
+                self.box_column = self.box_column.saturating_sub(1)
+            }
+            // Clamped to the columns that fit when drawn
+            Action::ScrollRight if self.tabs.tab == Tab::Boxscore => self.box_column += 1,
+            Action::ScrollLeft | Action::ScrollRight => {}
+            Action::NextTeam => self.next_team(),
+            Action::PrevTeam => self.previous_team(),
+            Action::Select => self.on_enter(),
+            Action::ToggleDivision => {
+                if self.tabs.tab == Tab::Standings {
+                    self.by_division = !self.by_division;
+                }
+            }
+            Action::NextPeriod => {
+                if self.tabs.tab == Tab::Boxscore {
+                    let i = self.box_segment.map_or(0, |x| {
+                        Segment::ALL.iter().position(|&s| s == x).unwrap() + 1
+                    });
+                    self.box_segment = Segment::ALL.get(i).copied();
+                    self.update_segment();
+                } else if self.tabs.tab == Tab::Shots {
+                    let i = self
+                        .shot_period
+                        .map_or(0, |x| Period::ALL.iter().position(|&p| p == x).unwrap() + 1);
+                    self.shot_period = Period::ALL.get(i).copied();
+                }
+            }
+            Action::Refresh => self.refresh(),
+            Action::Help => self.show_help = true,
+            Action::Quit => {}
+        }
+    }
+
+    pub fn on_mouse(&mut self, event: MouseEvent) {
+        let (column, row) = (event.column, event.row);
+        match event.kind {
+            MouseEventKind::Down(MouseButton::Left) if self.show_help || self.show_player => {
+                self.show_help = false;
+                self.show_player = false;
+            }
+            MouseEventKind::Down(MouseButton::Left) => {
+                if let Some(i) = hit(&self.areas.tabs, column, row) {
+                    self.tabs.tab = Tab::ALL[i];
+                    return;
+                }
+                match self.tabs.tab {
+                    Tab::Game if contains(self.areas.plays, column, row) => {
+                        self.plays.select_row((row - self.areas.plays.y) as usize);
+                    }
+                    tab if tab.has_team_tabs() => {
+                        if let Some(i) = hit(&self.areas.team_tabs, column, row) {
+                            let team = match i {
+                                0 => TabTeam::Home,
+                                _ => TabTeam::Visitor,
+                            };
+                            if team != self.tabs.team {
+                                self.tabs.team = team;
+                                self.select_player(None);
+                                self.shooter = None;
+                            }
+                        } else if tab == Tab::Boxscore && contains(self.areas.players, column, row)
+                        {
+                            let i = self.player_offset + (row - self.areas.players.y) as usize;
+                            if i < self.team_players().len() {
+                                self.player = Some(i);
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            MouseEventKind::ScrollUp => self.on_up(),
+            MouseEventKind::ScrollDown => self.on_down(),
             _ => {}
         }
     }
 
     pub fn on_tick(&mut self) {
-        // TODO: Update progress
+        self.spinner = self.spinner.wrapping_add(1);
+        if self.last_refresh.elapsed() >= self.refresh_rate {
+            let live = self.date_games().iter().any(|x| x.status_num() == 2);
+            if self.tabs.tab == Tab::Scoreboard || live {
+                self.request_scoreboard(false);
+            }
+            self.request_game(false);
+        }
+    }
+
+    pub fn on_update(&mut self, update: Update) {
+        match update {
+            Update::Loading(seq, r) | Update::Failed(seq, r, _) | Update::Unchanged(seq, r)
+                if self.is_stale(seq, r) => {}
+            Update::Schedule(seq, ..) if self.is_stale(seq, Resource::Schedule) => {}
+            Update::Scoreboard(seq, ..) if self.is_stale(seq, Resource::Scoreboard) => {}
+            Update::BoxScore(seq, ref game_id, ..)
+                if self.is_stale(seq, Resource::BoxScore)
+                    || self.game_id.as_ref() != Some(game_id) => {}
+            Update::PlayByPlay(seq, ref game_id, ..)
+                if self.is_stale(seq, Resource::PlayByPlay)
+                    || self.game_id.as_ref() != Some(game_id) => {}
+            // Only sent for the roster, fetched along with the boxscore and
+            // shown as loading when the boxscore is
+            Update::Loading(_, r) => {
+                if let Status::Loading = self.status(Resource::BoxScore) {
+                    self.set_status(r, Status::Loading);
+                }
+            }
+            Update::Failed(_, r, e) => self.set_status(r, Status::Failed(e.to_string())),
+            Update::Unchanged(_, r) => {
+                self.set_status(r, Status::Loaded);
+                if !matches!(r, Resource::Schedule | Resource::Scoreboard) {
+                    self.last_update = Some(Instant::now());
+                }
+            }
+            Update::Schedule(_, json, schedule) => {
+                self.set_status(Resource::Schedule, Status::Loaded);
+                let first_load = self.schedule.is_none();
+                if first_load {
+                    let games = self.sort_favorites(schedule.get_date_games(&self.date));
+                    self.game_id = games.first().map(|x| x.game_id().to_string());
+                    let team_id = self
+                        .favorites
+                        .first()
+                        .copied()
+                        .or_else(|| games.first().map(|x| x.h_team().team_id()));
+                    if let Some(team_id) = team_id {
+                        self.team = TEAMS.iter().position(|x| x.team_id == team_id).unwrap_or(0);
+                    }
+                }
+                self.standings = Some(Standings::new(&schedule));
+                self.schedule = Some(schedule);
+                self.set_json(Resource::Schedule, Some(json));
+                if first_load {
+                    self.request_game(false);
+                }
+            }
+            Update::Scoreboard(_, json, scores) => {
+                self.set_status(Resource::Scoreboard, Status::Loaded);
+                self.scores = Some(scores);
+                self.set_json(Resource::Scoreboard, Some(json));
+            }
+            Update::BoxScore(_, _, json, boxscore) => {
+                self.set_status(Resource::BoxScore, Status::Loaded);
+                if let Status::Loading | Status::Failed(_) = self.status(Resource::Roster) {
+                    self.set_status(Resource::Roster, Status::Loaded);
+                }
+                self.boxscore = Some(*boxscore);
+                self.update_lineups();
+                self.set_json(Resource::BoxScore, Some(json));
+                self.last_update = Some(Instant::now());
+            }
+            Update::PlayByPlay(_, _, json, pbp) => {
+                self.set_status(Resource::PlayByPlay, Status::Loaded);
+                self.flow = GameFlow::new(&pbp.plays);
+                self.plays.set_items(pbp.plays);
+                self.update_lineups();
+                self.set_json(Resource::PlayByPlay, Some(json));
+                self.last_update = Some(Instant::now());
+            }
+            Update::Profile(person_id, Some(profile)) => {
+                self.profile_failures.remove(&person_id);
+                self.profiles.insert(person_id, profile);
+            }
+            Update::Profile(person_id, None) => {
+                self.profile_failures.insert(person_id, Instant::now());
+            }
+        }
+    }
+
+    fn update_lineups(&mut self) {
+        self.lineups = match &self.boxscore {
+            Some(boxscore) => Lineups::new(boxscore, &self.plays.items),
+            None => Lineups::default(),
+        };
+        self.update_segment();
+    }
+
+    fn update_segment(&mut self) {
+        let boxscore = match &self.boxscore {
+            Some(x) => x,
+            None => return,
+        };
+        let plays = &self.plays.items;
+        self.segment_mismatches =
+            SegmentStats::new(boxscore, plays, &self.lineups, None).mismatches(boxscore);
+        self.segment_stats = self
+            .box_segment
+            .map(|x| SegmentStats::new(boxscore, plays, &self.lineups, Some(x)));
+    }
+
+    fn set_json(&mut self, resource: Resource, json: Option<Json>) {
+        self.json[resource as usize] = json;
+    }
+
+    fn set_status(&mut self, resource: Resource, status: Status) {
+        self.statuses[resource as usize] = status;
+    }
+
+    /// Whether `seq` is older than the last request of `resource`.
+    fn is_stale(&self, seq: u64, resource: Resource) -> bool {
+        seq < self.requests[resource as usize]
+    }
+
+    /// Numbers the next request of `resources`.
+    fn next_seq(&mut self, resources: &[Resource]) -> u64 {
+        self.seq += 1;
+        for &r in resources {
+            self.requests[r as usize] = self.seq;
+        }
+        self.seq
+    }
+
+    /// Sends `request`, its resources being shown as loading when they have
+    /// no data yet or on a `manual` refresh. Background polls leave them as
+    /// they are, their responses only updating `last_update`.
+    fn request(&mut self, request: Request, manual: bool) {
+        let resources: &[Resource] = match request {
+            Request::Schedule { .. } => &[Resource::Schedule],
+            Request::Scoreboard { .. } => &[Resource::Scoreboard],
+            Request::Game { .. } => &[Resource::BoxScore, Resource::PlayByPlay],
+            Request::Profile { .. } => &[],
+        };
+        for &r in resources {
+            if manual || !self.is_loaded(r) {
+                self.set_status(r, Status::Loading);
+            }
+        }
+        let _ = self.network.send(request);
+    }
+
+    /// Requests the schedule, the scoreboard and the current game.
+    fn refresh(&mut self) {
+        let seq = self.next_seq(&[Resource::Schedule]);
+        self.request(
+            Request::Schedule {
+                seq,
+                schedule: self.json[Resource::Schedule as usize],
+            },
+            true,
+        );
+        self.request_scoreboard(true);
+        self.request_game(true);
+    }
+
+    /// Requests the games of `date` from the scoreboard.
+    fn request_scoreboard(&mut self, manual: bool) {
+        let seq = self.next_seq(&[Resource::Scoreboard]);
+        self.request(
+            Request::Scoreboard {
+                seq,
+                date: self.date.clone(),
+                scoreboard: self.json[Resource::Scoreboard as usize],
+            },
+            manual,
+        );
+    }
+
+    /// Requests the current game, the schedule only being fetched at startup
+    /// and on a manual refresh, the scoreboard also on ticks while the
+    /// scoreboard tab is open or a game of the date is live.
+    fn request_game(&mut self, manual: bool) {
+        if let Some(game_id) = self.game_id.clone() {
+            let seq = self.next_seq(&[Resource::BoxScore, Resource::PlayByPlay, Resource::Roster]);
+            self.request(
+                Request::Game {
+                    seq,
+                    date: self.game_date.clone(),
+                    game_id,
+                    boxscore: self.json[Resource::BoxScore as usize],
+                    plays: self.json[Resource::PlayByPlay as usize],
+                },
+                manual,
+            );
+        }
+        self.last_refresh = Instant::now();
     }
 
     pub fn get_current_team(&self) -> &str {
-        match self.tabs.team {
-            TabTeam::Home => self.boxscore.h_team.team_id,
-            TabTeam::Visitor => self.boxscore.v_team.team_id,
+        match (&self.boxscore, self.tabs.team) {
+            (Some(b), TabTeam::Home) => b.h_team.team_id,
+            (Some(b), TabTeam::Visitor) => b.v_team.team_id,
+            (None, _) => "",
+        }
+    }
+}
+
+/// Tabs of the ui, in the order they are shown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tab {
+    Game,
+    Boxscore,
+    Scoreboard,
+    Standings,
+    Bracket,
+    Team,
+    Compare,
+    Lineups,
+    Rotation,
+    Shots,
+}
+
+impl Tab {
+    pub const ALL: [Tab; 10] = [
+        Tab::Game,
+        Tab::Boxscore,
+        Tab::Scoreboard,
+        Tab::Standings,
+        Tab::Bracket,
+        Tab::Team,
+        Tab::Compare,
+        Tab::Lineups,
+        Tab::Rotation,
+        Tab::Shots,
+    ];
+
+    pub fn title(self) -> &'static str {
+        match self {
+            Tab::Game => "Game",
+            Tab::Boxscore => "Boxscore",
+            Tab::Scoreboard => "Scoreboard",
+            Tab::Standings => "Standings",
+            Tab::Bracket => "Bracket",
+            Tab::Team => "Team",
+            Tab::Compare => "Compare",
+            Tab::Lineups => "Lineups",
+            Tab::Rotation => "Rotation",
+            Tab::Shots => "Shots",
+        }
+    }
+
+    /// Whether the tab shows the current game rather than the schedule.
+    pub fn is_game(self) -> bool {
+        matches!(
+            self,
+            Tab::Game | Tab::Boxscore | Tab::Compare | Tab::Lineups | Tab::Rotation | Tab::Shots
+        )
+    }
+
+    /// Whether the tab shows one team of the current game at a time.
+    pub fn has_team_tabs(self) -> bool {
+        matches!(
+            self,
+            Tab::Boxscore | Tab::Lineups | Tab::Rotation | Tab::Shots
+        )
+    }
+
+    fn index(self) -> usize {
+        Tab::ALL.iter().position(|&x| x == self).unwrap()
+    }
+}
+
+/// Delay before fetching a profile that failed again.
+const PROFILE_RETRY: Duration = Duration::from_secs(60);
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum TabTeam {
+    Home,
+    Visitor,
+}
+
+pub struct TabsState {
+    pub tab: Tab,
+    pub team: TabTeam,
+}
+
+impl TabsState {
+    pub fn new() -> TabsState {
+        TabsState {
+            tab: Tab::Game,
+            team: TabTeam::Home,
+        }
+    }
+
+    /// Index of the current tab in `Tab::ALL`.
+    pub fn index(&self) -> usize {
+        self.tab.index()
+    }
+
+    pub fn next(&mut self) {
+        self.tab = Tab::ALL[(self.index() + 1) % Tab::ALL.len()];
+    }
+
+    pub fn next_team(&mut self) {
+        self.team = match self.team {
+            TabTeam::Home => TabTeam::Visitor,
+            TabTeam::Visitor => TabTeam::Home,
+        }
+    }
+
+    pub fn previous(&mut self) {
+        self.tab = Tab::ALL[(self.index() + Tab::ALL.len() - 1) % Tab::ALL.len()];
+    }
+}
+
+/// Screen areas of the clickable parts of the ui.
+#[derive(Default)]
+pub struct Areas {
+    /// Titles of the main tabs.
+    pub tabs: Vec<Rect>,
+    /// Titles of the boxscore team tabs.
+    pub team_tabs: Vec<Rect>,
+    /// Rows of the play by play list.
+    pub plays: Rect,
+    /// Rows of the boxscore table, without the header.
+    pub players: Rect,
+}
+
+fn contains(area: Rect, column: u16, row: u16) -> bool {
+    column >= area.x && column < area.right() && row >= area.y && row < area.bottom()
+}
+
+/// Index of the area containing the position.
+fn hit(areas: &[Rect], column: u16, row: u16) -> Option<usize> {
+    areas.iter().position(|&x| contains(x, column, row))
+}
+
+/// First visible row of a list or table `height` rows high, scrolled the way
+/// tui scrolls its stateful widgets to keep `selected` in view.
+pub fn scroll_offset(offset: usize, selected: Option<usize>, height: usize) -> usize {
+    let height = height.max(1);
+    match selected {
+        Some(i) if i >= offset + height => i + 1 - height,
+        Some(i) if i < offset => i,
+        Some(_) => offset,
+        None => 0,
+    }
+}
+
+pub struct StatefulList<T> {
+    pub state: ListState,
+    pub items: Vec<T>,
+    offset: usize,
+}
+
+impl<T> StatefulList<T> {
+    pub fn with_items(items: Vec<T>) -> StatefulList<T> {
+        StatefulList {
+            state: ListState::default(),
+            items,
+            offset: 0,
+        }
+    }
+
+    /// Replaces the items, keeping the selection when it is still in range.
+    pub fn set_items(&mut self, items: Vec<T>) {
+        self.items = items;
+        match self.state.selected() {
+            Some(_) if self.items.is_empty() => self.state.select(None),
+            Some(i) if i >= self.items.len() => self.state.select(Some(self.items.len() - 1)),
+            _ => {}
+        }
+    }
+
+    pub fn next(&mut self) {
+        if self.items.is_empty() {
+            return;
+        }
+        let i = match self.state.selected() {
+            Some(i) => {
+                if i >= self.items.len() - 1 {
+                    0
+                } else {
+                    i + 1
+                }
+            }
+            None => 0,
+        };
+        self.state.select(Some(i));
+    }
+
+    pub fn previous(&mut self) {
+        if self.items.is_empty() {
+            return;
+        }
+        let i = match self.state.selected() {
+            Some(i) => {
+                if i == 0 {
+                    self.items.len() - 1
+                } else {
+                    i - 1
+                }
+            }
+            None => 0,
+        };
+        self.state.select(Some(i));
+    }
+
+    /// Updates the first visible row for a list `height` rows high.
+    pub fn scroll(&mut self, height: usize) {
+        self.offset = scroll_offset(self.offset, self.state.selected(), height);
+    }
+
+    /// Selects the item shown on the `row`th visible row.
+    pub fn select_row(&mut self, row: usize) {
+        if self.offset + row < self.items.len() {
+            self.state.select(Some(self.offset + row));
         }
     }
 }
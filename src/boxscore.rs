@@ -1,45 +1,235 @@
 use crate::utils::*;
+use crate::Error;
 use reqwest::blocking::Client;
 use serde::de::Deserialize;
 use serde_derive::Deserialize;
 
+/// Boxscore of a single game.
+///
+/// Before tip-off the feed has no stats, the players are then the rosters of
+/// both teams.
 #[derive(Debug)]
 pub struct BoxScore<'lf> {
+    /// Players who appeared in the game, or the rosters before tip-off.
     pub players: Vec<Player<'lf>>,
+    /// The visiting team.
     pub v_team: Team<'lf>,
+    /// The home team.
     pub h_team: Team<'lf>,
+    clock: &'lf str,
+    period: Period,
+    playoffs: Option<Playoffs<'lf>>,
+    stats: Option<GameStats<'lf>>,
+}
+
+fn url(game_date: &str, game_id: &str) -> String {
+    format!(
+        "http://data.nba.com/prod/v1/{}/{}_boxscore.json",
+        game_date, game_id
+    )
 }
 
 impl<'lf> BoxScore<'lf> {
+    /// Fetches the boxscore, along with the league roster before tip-off,
+    /// blocking version of `fetch`.
     pub fn new(
         client: &reqwest::blocking::Client,
         game_date: &str,
         game_id: &str,
-    ) -> Result<Self, Box<dyn std::error::Error>> {
+    ) -> Result<Self, Error> {
         let boxscore = Box::leak::<'lf>(Box::new(
-            client
-                .get(format!(
-                    "http://data.nba.com/prod/v1/{}/{}_boxscore.json",
-                    game_date, game_id
-                ))
-                .send()?
-                .text()?,
+            client.get(url(game_date, game_id)).send()?.text()?,
         ));
-        // let boxscore = Box::leak::<'lf>(Box::new(
-        //     std::fs::read_to_string(
-        //         std::path::PathBuf::from(format!("{}", std::env!("CARGO_MANIFEST_DIR")))
-        //             .join("src/boxscore.json"),
-        //     )
-        //     .unwrap(),
-        // ));
 
-        let boxscore = serde_json::from_str::<BoxScore<'lf>>(boxscore)?;
+        let mut boxscore = serde_json::from_str::<BoxScore<'lf>>(boxscore)?;
+        if boxscore.needs_roster() {
+            boxscore.set_roster(&Player::roster(client)?);
+        }
 
         Ok(boxscore)
     }
 
-    pub fn update(&mut self, client: &Client, date: &str, id: &str) {
-        *self = Self::new(client, date, id).unwrap();
+    /// Fetches the boxscore, along with the league roster before tip-off.
+    pub async fn fetch(
+        client: &reqwest::Client,
+        game_date: &str,
+        game_id: &str,
+    ) -> Result<Self, Error> {
+        let mut boxscore = Self::fetch_feed(client, game_date, game_id).await?;
+        if boxscore.needs_roster() {
+            boxscore.set_roster(&Player::fetch_roster(client).await?);
+        }
+
+        Ok(boxscore)
+    }
+
+    /// Fetches the boxscore without filling the players from the roster,
+    /// see `needs_roster`.
+    pub async fn fetch_feed(
+        client: &reqwest::Client,
+        game_date: &str,
+        game_id: &str,
+    ) -> Result<Self, Error> {
+        let boxscore = Box::leak::<'lf>(Box::new(
+            Self::fetch_json(client, game_date, game_id).await?,
+        ));
+
+        Self::from_json(boxscore)
+    }
+
+    /// Fetches the json of the boxscore feed, to be parsed by `from_json`.
+    pub async fn fetch_json(
+        client: &reqwest::Client,
+        game_date: &str,
+        game_id: &str,
+    ) -> Result<String, Error> {
+        Ok(client
+            .get(url(game_date, game_id))
+            .send()
+            .await?
+            .text()
+            .await?)
+    }
+
+    /// Parses the json of the boxscore feed.
+    pub fn from_json(json: &'lf str) -> Result<Self, Error> {
+        Ok(serde_json::from_str::<BoxScore<'lf>>(json)?)
+    }
+
+    /// Whether the game has not started and the players have to be taken
+    /// from the league roster.
+    pub fn needs_roster(&self) -> bool {
+        self.stats.is_none() && self.players.is_empty()
+    }
+
+    /// Sets the players to the home and visitor players of `roster`.
+    pub fn set_roster(&mut self, roster: &[Player<'lf>]) {
+        self.players = [self.h_team.team_id, self.v_team.team_id]
+            .iter()
+            .flat_map(|&id| roster.iter().filter(move |x| x.team_id == Some(id)))
+            .cloned()
+            .collect();
+    }
+
+    /// Fetches the boxscore again, blocking.
+    pub fn update(&mut self, client: &Client, date: &str, id: &str) -> Result<(), Error> {
+        *self = Self::new(client, date, id)?;
+        Ok(())
+    }
+
+    /// Game clock of the current period, empty when the clock is stopped
+    /// between periods.
+    pub fn clock(&self) -> &'lf str {
+        self.clock
+    }
+
+    /// Current period of the game.
+    pub fn period(&self) -> &Period {
+        &self.period
+    }
+
+    /// Series information, `None` outside of the playoffs.
+    pub fn playoffs(&self) -> Option<&Playoffs<'lf>> {
+        self.playoffs.as_ref()
+    }
+
+    /// Game wide stats, `None` before tip-off.
+    pub fn stats(&self) -> Option<&GameStats<'lf>> {
+        self.stats.as_ref()
+    }
+
+    /// Totals of the team `team_id`, `None` before tip-off.
+    pub fn totals(&self, team_id: &str) -> Option<&Totals<'lf>> {
+        let stats = self.stats.as_ref()?;
+        if team_id == self.h_team.team_id {
+            Some(stats.h_team.totals())
+        } else if team_id == self.v_team.team_id {
+            Some(stats.v_team.totals())
+        } else {
+            None
+        }
+    }
+}
+
+/// Game wide stats of a boxscore.
+#[derive(Debug)]
+pub struct GameStats<'lf> {
+    times_tied: &'lf str,
+    lead_changes: &'lf str,
+    v_team: TeamStats<'lf>,
+    h_team: TeamStats<'lf>,
+}
+
+impl<'lf> GameStats<'lf> {
+    /// Number of times the score was tied.
+    pub fn times_tied(&self) -> &'lf str {
+        self.times_tied
+    }
+
+    /// Number of lead changes.
+    pub fn lead_changes(&self) -> &'lf str {
+        self.lead_changes
+    }
+
+    /// Stats of the visiting team.
+    pub fn v_team(&self) -> &TeamStats<'lf> {
+        &self.v_team
+    }
+
+    /// Stats of the home team.
+    pub fn h_team(&self) -> &TeamStats<'lf> {
+        &self.h_team
+    }
+}
+
+/// Stats of a team in a boxscore.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+#[serde(bound(deserialize = "'de: 'lf"))]
+pub struct TeamStats<'lf> {
+    longest_run: &'lf str,
+    fast_break_points: Option<&'lf str>,
+    points_in_paint: Option<&'lf str>,
+    second_chance_points: Option<&'lf str>,
+    points_off_turnovers: Option<&'lf str>,
+    totals: Totals<'lf>,
+    leaders: Option<Leaders<'lf>>,
+}
+
+impl<'lf> TeamStats<'lf> {
+    /// Most unanswered points the team scored.
+    pub fn longest_run(&self) -> &'lf str {
+        self.longest_run
+    }
+
+    /// Points scored on fast breaks.
+    pub fn fast_break_points(&self) -> Option<&'lf str> {
+        self.fast_break_points
+    }
+
+    /// Points scored in the paint.
+    pub fn points_in_paint(&self) -> Option<&'lf str> {
+        self.points_in_paint
+    }
+
+    /// Points scored after an offensive rebound.
+    pub fn second_chance_points(&self) -> Option<&'lf str> {
+        self.second_chance_points
+    }
+
+    /// Points scored after an opponent turnover.
+    pub fn points_off_turnovers(&self) -> Option<&'lf str> {
+        self.points_off_turnovers
+    }
+
+    /// Stat totals of the team.
+    pub fn totals(&self) -> &Totals<'lf> {
+        &self.totals
+    }
+
+    /// Points, rebounds and assists leaders, `None` before tip-off.
+    pub fn leaders(&self) -> Option<&Leaders<'lf>> {
+        self.leaders.as_ref()
     }
 }
 
@@ -59,54 +249,58 @@ where
             stats: Option<Stats<'lf>>,
         }
 
-        #[derive(Deserialize, Debug)]
+        #[derive(Deserialize)]
         #[serde(rename_all = "camelCase")]
         #[serde(bound(deserialize = "'de: 'lf"))]
-        pub struct BasicGameData<'lf> {
+        struct BasicGameData<'lf> {
             clock: &'lf str,
             playoffs: Option<Playoffs<'lf>>,
             period: Period,
-            pub v_team: Team<'lf>,
-            pub h_team: Team<'lf>,
+            v_team: Team<'lf>,
+            h_team: Team<'lf>,
         }
-        #[derive(Deserialize, Debug)]
+        #[derive(Deserialize)]
         #[serde(rename_all = "camelCase")]
         #[serde(bound(deserialize = "'de: 'lf"))]
-        pub struct Stats<'lf> {
+        struct Stats<'lf> {
             times_tied: &'lf str,
             lead_changes: &'lf str,
-            v_team: StatTeam<'lf>,
-            h_team: StatTeam<'lf>,
-            pub active_players: Vec<Player<'lf>>,
-        }
-        #[derive(Deserialize, Debug)]
-        #[serde(rename_all = "camelCase")]
-        #[serde(bound(deserialize = "'de: 'lf"))]
-        struct StatTeam<'lf> {
-            longest_run: &'lf str,
+            v_team: TeamStats<'lf>,
+            h_team: TeamStats<'lf>,
+            active_players: Vec<Player<'lf>>,
         }
 
         let helper = Root::deserialize(deserializer)?;
-        let players = if let Some(x) = helper.stats {
-            x.active_players
+        // Players are left empty before tip-off and filled from the roster
+        let (players, stats) = if let Some(x) = helper.stats {
+            (
+                x.active_players,
+                Some(GameStats {
+                    times_tied: x.times_tied,
+                    lead_changes: x.lead_changes,
+                    v_team: x.v_team,
+                    h_team: x.h_team,
+                }),
+            )
         } else {
-            [
-                Player::from_team_id(helper.bgd.h_team.team_id),
-                Player::from_team_id(helper.bgd.v_team.team_id),
-            ]
-            .concat()
+            (Vec::new(), None)
         };
 
         Ok(Self {
             players,
             v_team: helper.bgd.v_team,
             h_team: helper.bgd.h_team,
+            clock: helper.bgd.clock,
+            period: helper.bgd.period,
+            playoffs: helper.bgd.playoffs,
+            stats,
         })
     }
 }
 
+/// Playoff series information of a boxscore.
 #[derive(Debug)]
-struct Playoffs<'lf> {
+pub struct Playoffs<'lf> {
     round_num: &'lf str,
     conf_name: &'lf str,
     series_id: &'lf str,
@@ -117,6 +311,48 @@ struct Playoffs<'lf> {
     h_team_seed: u8,
 }
 
+impl<'lf> Playoffs<'lf> {
+    /// Round of the series, from `1` to `4`.
+    pub fn round_num(&self) -> &'lf str {
+        self.round_num
+    }
+
+    /// `East`, `West` or `NBA` for the finals.
+    pub fn conf_name(&self) -> &'lf str {
+        self.conf_name
+    }
+
+    /// Id shared by every game of the series.
+    pub fn series_id(&self) -> &'lf str {
+        self.series_id
+    }
+
+    /// Whether the series is over.
+    pub fn completed(&self) -> bool {
+        self.completed
+    }
+
+    /// Number of the game in the series.
+    pub fn num_in_series(&self) -> &'lf str {
+        self.num_in_series
+    }
+
+    /// Whether the game is only played if the series is not decided yet.
+    pub fn if_necessary(&self) -> bool {
+        self.if_necessary
+    }
+
+    /// Seed of the visiting team.
+    pub fn v_team_seed(&self) -> u8 {
+        self.v_team_seed
+    }
+
+    /// Seed of the home team.
+    pub fn h_team_seed(&self) -> u8 {
+        self.h_team_seed
+    }
+}
+
 impl<'lf, 'de> Deserialize<'de> for Playoffs<'lf>
 where
     'de: 'lf,
@@ -160,9 +396,12 @@ where
     }
 }
 
+/// A team of a boxscore.
 #[derive(Debug)]
 pub struct Team<'lf> {
+    /// Id of the team.
     pub team_id: &'lf str,
+    /// Three letter code of the team, e.g. `LAL`.
     pub tri_code: &'lf str,
     win: u8,
     loss: u8,
@@ -170,6 +409,28 @@ pub struct Team<'lf> {
     linescore: [u8; 4],
 }
 
+impl<'lf> Team<'lf> {
+    /// Wins of the team, this game included once final.
+    pub fn win(&self) -> u8 {
+        self.win
+    }
+
+    /// Losses of the team, this game included once final.
+    pub fn loss(&self) -> u8 {
+        self.loss
+    }
+
+    /// Points scored.
+    pub fn score(&self) -> u8 {
+        self.score
+    }
+
+    /// Points scored in each of the four quarters.
+    pub fn linescore(&self) -> [u8; 4] {
+        self.linescore
+    }
+}
+
 impl<'lf, 'de> Deserialize<'de> for Team<'lf>
 where
     'de: 'lf,
@@ -206,7 +467,7 @@ where
             linescore: [
                 helper
                     .linescore
-                    .get(0)
+                    .first()
                     .unwrap_or(&LineScore { score: "0" })
                     .score
                     .parse()
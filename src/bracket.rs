@@ -0,0 +1,112 @@
+use crate::schedule::{Game, Schedule};
+
+/// Order of the seeds in a conference bracket, top to bottom.
+const BRACKET_ORDER: [u8; 8] = [1, 8, 4, 5, 3, 6, 2, 7];
+
+/// A playoff series and its games so far.
+#[derive(Debug)]
+pub struct Series<'a, 'lf> {
+    /// Id shared by every game of the series.
+    pub series_id: &'lf str,
+    /// Round of the series, from `1` to `4`.
+    pub round: u8,
+    /// `East`, `West` or `NBA` for the finals.
+    pub conference: &'lf str,
+    /// Team with home court advantage, home team of the first game.
+    pub top_team: &'lf str,
+    /// Seed of `top_team`.
+    pub top_seed: u8,
+    /// Games won by `top_team`.
+    pub top_wins: u8,
+    /// The other team of the series.
+    pub bottom_team: &'lf str,
+    /// Seed of `bottom_team`.
+    pub bottom_seed: u8,
+    /// Games won by `bottom_team`.
+    pub bottom_wins: u8,
+    /// Whether the series is over.
+    pub completed: bool,
+    /// Games of the series in chronological order.
+    pub games: Vec<&'a Game<'lf>>,
+}
+
+impl<'a, 'lf> Series<'a, 'lf> {
+    /// Team id of the team advancing out of the series.
+    pub fn winner(&self) -> Option<&'lf str> {
+        if !self.completed && self.top_wins < 4 && self.bottom_wins < 4 {
+            None
+        } else if self.top_wins > self.bottom_wins {
+            Some(self.top_team)
+        } else {
+            Some(self.bottom_team)
+        }
+    }
+
+    fn bracket_position(&self) -> usize {
+        let seed = self.top_seed.min(self.bottom_seed);
+        BRACKET_ORDER
+            .iter()
+            .position(|&x| x == seed)
+            .unwrap_or(BRACKET_ORDER.len())
+    }
+}
+
+/// Groups the playoff games of `schedule` by series, ordered by round,
+/// conference and bracket position.
+pub fn series<'a, 'lf>(schedule: &'a Schedule<'lf>) -> Vec<Series<'a, 'lf>> {
+    let mut games: Vec<&Game> = schedule
+        .games()
+        .iter()
+        .filter(|x| x.season_stage_id() == 4 && x.playoffs().is_some())
+        .collect();
+    games.sort_by_key(|x| x.start_time_utc());
+
+    let mut series: Vec<Series> = Vec::new();
+    for game in games {
+        let playoffs = game.playoffs().unwrap();
+        let index = match series
+            .iter()
+            .position(|x| x.series_id == playoffs.series_id())
+        {
+            Some(index) => index,
+            None => {
+                series.push(Series {
+                    series_id: playoffs.series_id(),
+                    round: playoffs.round_num().parse().unwrap_or(0),
+                    conference: playoffs.conf_name(),
+                    top_team: game.h_team().team_id(),
+                    top_seed: playoffs.h_team_seed(),
+                    top_wins: 0,
+                    bottom_team: game.v_team().team_id(),
+                    bottom_seed: playoffs.v_team_seed(),
+                    bottom_wins: 0,
+                    completed: false,
+                    games: Vec::new(),
+                });
+                series.len() - 1
+            }
+        };
+        let series = &mut series[index];
+        match game.winner() {
+            Some(id) if id == series.top_team => series.top_wins += 1,
+            Some(id) if id == series.bottom_team => series.bottom_wins += 1,
+            _ => {}
+        }
+        series.completed |= playoffs.completed();
+        series.games.push(game);
+    }
+
+    let conference_order = |x: &str| match x {
+        "East" => 0,
+        "West" => 1,
+        _ => 2,
+    };
+    series.sort_by_key(|x| {
+        (
+            x.round,
+            conference_order(x.conference),
+            x.bracket_position(),
+        )
+    });
+    series
+}
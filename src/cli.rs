@@ -0,0 +1,218 @@
+use crate::columns::{self, COLUMNS};
+use crate::config::Config;
+use nil::boxscore::BoxScore;
+use nil::lineups::Lineups;
+use nil::pbp::PlayByPlay;
+use nil::schedule::{Game, Schedule};
+use nil::standings::{format_games_behind, format_pct, Standing, Standings};
+use nil::stats::Stats;
+use nil::teams::{Conference, Division};
+use nil::{Error, Player};
+
+/// Prints the conference standings, or the division standings with
+/// `by_division`.
+pub fn standings(by_division: bool) -> Result<(), Error> {
+    let client = reqwest::blocking::Client::new();
+    let schedule = Schedule::new(&client)?;
+    let standings = Standings::new(&schedule);
+
+    if by_division {
+        for division in Division::ALL.iter() {
+            print_standings(&division.to_string(), standings.division(*division));
+        }
+    } else {
+        for conference in [Conference::East, Conference::West].iter() {
+            print_standings(&conference.to_string(), standings.conference(*conference));
+        }
+    }
+
+    Ok(())
+}
+
+/// Prints the effective configuration, the file merged with the flags.
+pub fn config(config: &Config) -> Result<(), Error> {
+    if let Some(path) = Config::path() {
+        println!("# {}", path.display());
+    }
+    print!("{}", toml::to_string_pretty(config)?);
+    Ok(())
+}
+
+/// Table printed by `export`.
+pub enum Export {
+    /// Player stats with the given columns, every column when empty.
+    Boxscore(Vec<String>),
+    Lineups,
+    OnOff,
+}
+
+/// Prints a table of `game` on the config date as csv, `game` being a game
+/// id or the tri code of one of its teams, the first game by default.
+pub fn export(config: &Config, game: Option<&str>, export: Export) -> Result<(), Error> {
+    let client = reqwest::blocking::Client::new();
+    let schedule = Schedule::new(&client)?;
+    let date = config.date();
+    let games = schedule.get_date_games(&date);
+    let game = find_game(&games, game).ok_or_else(|| match game {
+        Some(game) => format!("no game `{}` on {}", game, date),
+        None => format!("no games on {}", date),
+    })?;
+    let game_date = game.start_date_eastern();
+    let boxscore = BoxScore::new(&client, game_date, game.game_id())?;
+
+    match export {
+        Export::Boxscore(columns) => print_boxscore(&boxscore, &columns),
+        Export::Lineups | Export::OnOff => {
+            let pbp = PlayByPlay::new(&client, game_date, game.game_id())?;
+            let lineups = Lineups::new(&boxscore, &pbp.plays);
+            match export {
+                Export::Lineups => print_lineups(&boxscore, &lineups),
+                _ => print_on_off(&boxscore, &lineups),
+            }
+            Ok(())
+        }
+    }
+}
+
+fn print_boxscore(boxscore: &BoxScore, columns: &[String]) -> Result<(), Error> {
+    let columns = if columns.is_empty() {
+        COLUMNS.iter().collect()
+    } else {
+        columns::from_ids(columns)?
+    };
+    let mut header = vec!["team", "player"];
+    header.extend(columns.iter().map(|x| x.id));
+    println!("{}", header.join(","));
+    for team in [&boxscore.v_team, &boxscore.h_team].iter() {
+        let totals = boxscore
+            .totals(team.team_id)
+            .map(Stats::from)
+            .unwrap_or_default();
+        for player in boxscore
+            .players
+            .iter()
+            .filter(|x| x.team_id == Some(team.team_id))
+        {
+            let mut row = vec![team.tri_code.to_string(), csv_field(&full_name(player))];
+            let stats = Stats::from(player);
+            row.extend(
+                columns
+                    .iter()
+                    .map(|x| csv_field(&(x.value)(player, &stats, &totals))),
+            );
+            println!("{}", row.join(","));
+        }
+    }
+
+    Ok(())
+}
+
+fn print_lineups(boxscore: &BoxScore, lineups: &Lineups) {
+    println!("team,players,minutes,points_for,points_against,plus_minus,net_rating");
+    for lineup in lineups.lineups.iter() {
+        let names: Vec<String> = lineup
+            .players
+            .iter()
+            .map(|x| player_name(boxscore, x))
+            .collect();
+        let stint = &lineup.stint;
+        println!(
+            "{},{},{:.2},{},{},{},{}",
+            tri_code(boxscore, lineup.home),
+            csv_field(&names.join(" / ")),
+            stint.minutes,
+            stint.points_for,
+            stint.points_against,
+            stint.plus_minus(),
+            format_rating(stint.net_rating()),
+        );
+    }
+}
+
+fn print_on_off(boxscore: &BoxScore, lineups: &Lineups) {
+    println!("team,player,on_minutes,on_plus_minus,on_net_rating,off_minutes,off_plus_minus,off_net_rating");
+    for x in lineups.on_off.iter() {
+        println!(
+            "{},{},{:.2},{},{},{:.2},{},{}",
+            tri_code(boxscore, x.home),
+            csv_field(&player_name(boxscore, x.person_id)),
+            x.on.minutes,
+            x.on.plus_minus(),
+            format_rating(x.on.net_rating()),
+            x.off.minutes,
+            x.off.plus_minus(),
+            format_rating(x.off.net_rating()),
+        );
+    }
+}
+
+fn tri_code<'lf>(boxscore: &BoxScore<'lf>, home: bool) -> &'lf str {
+    if home {
+        boxscore.h_team.tri_code
+    } else {
+        boxscore.v_team.tri_code
+    }
+}
+
+fn full_name(player: &Player) -> String {
+    format!("{} {}", player.first_name, player.last_name)
+}
+
+fn player_name(boxscore: &BoxScore, person_id: &str) -> String {
+    boxscore
+        .players
+        .iter()
+        .find(|x| x.person_id() == person_id)
+        .map_or_else(|| person_id.to_string(), full_name)
+}
+
+/// A rating with one decimal, empty when unknown.
+fn format_rating(rating: Option<f32>) -> String {
+    rating.map_or(String::new(), |x| format!("{:.1}", x))
+}
+
+fn find_game<'a, 'lf>(games: &[&'a Game<'lf>], game: Option<&str>) -> Option<&'a Game<'lf>> {
+    match game {
+        Some(game) => games.iter().copied().find(|x| {
+            x.game_id() == game
+                || x.h_tri_code().eq_ignore_ascii_case(game)
+                || x.v_tri_code().eq_ignore_ascii_case(game)
+        }),
+        None => games.first().copied(),
+    }
+}
+
+/// Quotes `value` when it contains a separator or a quote.
+fn csv_field(value: &str) -> String {
+    if value.contains(&[',', '"', '\n'][..]) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn print_standings(title: &str, table: &[Standing]) {
+    println!("{}", title);
+    println!(
+        "{: >2} {: <4} {: >3} {: >3} {: >5} {: >5} {: >6} {: >6} {: >6} {: >6} {: >6} {: >4}",
+        "#", "Team", "W", "L", "Pct", "GB", "Home", "Away", "Div", "Conf", "L10", "Strk"
+    );
+    for (i, s) in table.iter().enumerate() {
+        println!(
+            "{: >2} {: <4} {: >3} {: >3} {: >5} {: >5} {: >6} {: >6} {: >6} {: >6} {: >6} {: >4}",
+            i + 1,
+            s.team.tri_code,
+            s.record.wins,
+            s.record.losses,
+            format_pct(s.record.pct()),
+            format_games_behind(s.games_behind),
+            s.home.to_string(),
+            s.away.to_string(),
+            s.division.to_string(),
+            s.conference.to_string(),
+            s.last_10.to_string(),
+            s.streak.map(|x| x.to_string()).unwrap_or_default(),
+        );
+    }
+    println!();
+}
@@ -0,0 +1,286 @@
+use nil::stats::Stats;
+use nil::{Error, Player};
+
+/// A column of the boxscore table.
+pub struct Column {
+    /// Name used in the `boxscore_columns` setting.
+    pub id: &'static str,
+    pub header: &'static str,
+    pub width: u16,
+    /// The highest priorities are hidden first on narrow terminals, 0 and 1
+    /// never are.
+    pub priority: u8,
+    /// Whether the column is shown without a `boxscore_columns` setting.
+    pub default: bool,
+    /// Value of a player, given their stats and the totals of their team.
+    pub value: fn(&Player, &Stats, &Stats) -> String,
+}
+
+/// Minutes as a `MM:SS` clock.
+pub fn minutes(minutes: f32) -> String {
+    let seconds = (minutes * 60.0).round() as u32;
+    format!("{}:{:02}", seconds / 60, seconds % 60)
+}
+
+fn pct(value: f32) -> String {
+    format!("{:.1}", value)
+}
+
+fn plus_minus(value: i16) -> String {
+    if value > 0 {
+        format!("+{}", value)
+    } else {
+        value.to_string()
+    }
+}
+
+fn derived(value: Option<f32>) -> String {
+    value.map_or("-".to_string(), |x| format!("{:.1}", x))
+}
+
+pub static COLUMNS: [Column; 28] = [
+    Column {
+        id: "pos",
+        header: "P",
+        width: 3,
+        priority: 2,
+        default: true,
+        value: |p, _, _| p.pos.unwrap_or("").to_string(),
+    },
+    Column {
+        id: "jersey",
+        header: "#",
+        width: 3,
+        priority: 2,
+        default: false,
+        value: |p, _, _| p.jersey().unwrap_or("").to_string(),
+    },
+    Column {
+        id: "min",
+        header: "Min",
+        width: 5,
+        priority: 1,
+        default: true,
+        value: |_, s, _| minutes(s.minutes),
+    },
+    Column {
+        id: "pts",
+        header: "Pts",
+        width: 5,
+        priority: 0,
+        default: true,
+        value: |_, s, _| s.points.to_string(),
+    },
+    Column {
+        id: "reb",
+        header: "Reb",
+        width: 5,
+        priority: 0,
+        default: true,
+        value: |_, s, _| s.tot_reb.to_string(),
+    },
+    Column {
+        id: "ast",
+        header: "Ast",
+        width: 5,
+        priority: 0,
+        default: true,
+        value: |_, s, _| s.assists.to_string(),
+    },
+    Column {
+        id: "stl",
+        header: "Stl",
+        width: 5,
+        priority: 1,
+        default: true,
+        value: |_, s, _| s.steals.to_string(),
+    },
+    Column {
+        id: "blk",
+        header: "Blk",
+        width: 5,
+        priority: 1,
+        default: true,
+        value: |_, s, _| s.blocks.to_string(),
+    },
+    Column {
+        id: "blka",
+        header: "BlkA",
+        width: 5,
+        priority: 3,
+        default: false,
+        value: |_, s, _| s.blocks_against.to_string(),
+    },
+    Column {
+        id: "fgm",
+        header: "FGM",
+        width: 5,
+        priority: 3,
+        default: true,
+        value: |_, s, _| s.fgm.to_string(),
+    },
+    Column {
+        id: "fga",
+        header: "FGA",
+        width: 5,
+        priority: 3,
+        default: true,
+        value: |_, s, _| s.fga.to_string(),
+    },
+    Column {
+        id: "fgp",
+        header: "FG%",
+        width: 5,
+        priority: 1,
+        default: true,
+        value: |_, s, _| pct(s.fgp),
+    },
+    Column {
+        id: "tpm",
+        header: "3PM",
+        width: 5,
+        priority: 3,
+        default: true,
+        value: |_, s, _| s.tpm.to_string(),
+    },
+    Column {
+        id: "tpa",
+        header: "3PA",
+        width: 5,
+        priority: 3,
+        default: true,
+        value: |_, s, _| s.tpa.to_string(),
+    },
+    Column {
+        id: "tpp",
+        header: "3P%",
+        width: 5,
+        priority: 2,
+        default: true,
+        value: |_, s, _| pct(s.tpp),
+    },
+    Column {
+        id: "ftm",
+        header: "FTM",
+        width: 5,
+        priority: 3,
+        default: true,
+        value: |_, s, _| s.ftm.to_string(),
+    },
+    Column {
+        id: "fta",
+        header: "FTA",
+        width: 5,
+        priority: 3,
+        default: true,
+        value: |_, s, _| s.fta.to_string(),
+    },
+    Column {
+        id: "ftp",
+        header: "FT%",
+        width: 5,
+        priority: 2,
+        default: true,
+        value: |_, s, _| pct(s.ftp),
+    },
+    Column {
+        id: "oreb",
+        header: "OREB",
+        width: 5,
+        priority: 3,
+        default: true,
+        value: |_, s, _| s.off_reb.to_string(),
+    },
+    Column {
+        id: "dreb",
+        header: "DREB",
+        width: 5,
+        priority: 3,
+        default: true,
+        value: |_, s, _| s.def_reb.to_string(),
+    },
+    Column {
+        id: "tov",
+        header: "TOV",
+        width: 5,
+        priority: 1,
+        default: true,
+        value: |_, s, _| s.turnovers.to_string(),
+    },
+    Column {
+        id: "pf",
+        header: "PF",
+        width: 5,
+        priority: 2,
+        default: true,
+        value: |_, s, _| s.p_fouls.to_string(),
+    },
+    Column {
+        id: "pm",
+        header: "+/-",
+        width: 5,
+        priority: 1,
+        default: true,
+        value: |_, s, _| plus_minus(s.plus_minus),
+    },
+    Column {
+        id: "ts",
+        header: "TS%",
+        width: 5,
+        priority: 3,
+        default: false,
+        value: |_, s, _| derived(s.true_shooting()),
+    },
+    Column {
+        id: "efg",
+        header: "eFG%",
+        width: 5,
+        priority: 3,
+        default: false,
+        value: |_, s, _| derived(s.effective_fg()),
+    },
+    Column {
+        id: "ast_tov",
+        header: "A/TO",
+        width: 5,
+        priority: 3,
+        default: false,
+        value: |_, s, _| derived(s.assist_turnover()),
+    },
+    Column {
+        id: "gmsc",
+        header: "GmSc",
+        width: 5,
+        priority: 3,
+        default: false,
+        value: |_, s, _| derived(Some(s.game_score())),
+    },
+    Column {
+        id: "usg",
+        header: "USG%",
+        width: 5,
+        priority: 3,
+        default: false,
+        value: |_, s, team| derived(s.usage(team)),
+    },
+];
+
+/// Columns of `ids` in that order, the default ones when empty.
+pub fn from_ids(ids: &[String]) -> Result<Vec<&'static Column>, Error> {
+    if ids.is_empty() {
+        return Ok(COLUMNS.iter().filter(|x| x.default).collect());
+    }
+    ids.iter()
+        .map(|id| {
+            COLUMNS.iter().find(|x| x.id == id).ok_or_else(|| {
+                let ids: Vec<&str> = COLUMNS.iter().map(|x| x.id).collect();
+                format!(
+                    "unknown boxscore column `{}`, expected one of {}",
+                    id,
+                    ids.join(", ")
+                )
+                .into()
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,111 @@
+use crate::theme::ThemeColors;
+use clap::ArgMatches;
+use nil::{utils, Error};
+use serde_derive::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+
+/// User preferences read from `config.toml` in the `nil` XDG config
+/// directory, every key being optional.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    pub enhanced_graphics: bool,
+    /// Tick rate of the ui in milliseconds.
+    pub tick_rate: u64,
+    /// Date in yyyymmdd format, today when unset.
+    pub date: Option<String>,
+    /// Seconds between refreshes of the current game.
+    pub refresh: u64,
+    /// Tri codes of the favorite teams.
+    pub favorites: Vec<String>,
+    /// `local` or a tz database name.
+    pub timezone: String,
+    /// A built-in theme, `dark`, `light` or `high-contrast`, or one of
+    /// `themes`.
+    pub theme: String,
+    /// Whether teams are shown in their official colors.
+    pub team_colors: bool,
+    /// Ids of the boxscore columns to show in order, like `pts` or `blka`,
+    /// the default set when empty.
+    pub boxscore_columns: Vec<String>,
+    /// Key to action overrides of the default key bindings.
+    pub keys: BTreeMap<String, String>,
+    /// User defined themes.
+    pub themes: BTreeMap<String, ThemeColors>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            enhanced_graphics: true,
+            tick_rate: 250,
+            date: None,
+            refresh: 30,
+            favorites: Vec::new(),
+            timezone: "local".to_string(),
+            theme: "dark".to_string(),
+            team_colors: false,
+            boxscore_columns: Vec::new(),
+            keys: BTreeMap::new(),
+            themes: BTreeMap::new(),
+        }
+    }
+}
+
+impl Config {
+    pub fn path() -> Option<PathBuf> {
+        dirs::config_dir().map(|x| x.join("nil").join("config.toml"))
+    }
+
+    /// Reads the config file, defaults when it does not exist.
+    pub fn load() -> Result<Self, Error> {
+        match Config::path() {
+            Some(path) if path.exists() => {
+                let text = fs::read_to_string(&path)?;
+                toml::from_str(&text).map_err(|e| format!("{}: {}", path.display(), e).into())
+            }
+            _ => Ok(Config::default()),
+        }
+    }
+
+    /// Overrides the file with the flags given on the command line.
+    pub fn merge(&mut self, matches: &ArgMatches) -> Result<(), Error> {
+        if let Some(x) = matches.value_of("enhanced_graphics") {
+            self.enhanced_graphics = x.parse()?;
+        }
+        if let Some(x) = matches.value_of("tick_rate") {
+            self.tick_rate = x.parse()?;
+        }
+        if let Some(x) = matches.value_of("date") {
+            self.date = Some(x.to_string());
+        }
+        if let Some(x) = matches.value_of("refresh") {
+            self.refresh = x.parse()?;
+        }
+        if let Some(x) = matches.values_of("favorites") {
+            self.favorites = x.map(str::to_string).collect();
+        }
+        if let Some(x) = matches.value_of("timezone") {
+            self.timezone = x.to_string();
+        }
+        Ok(())
+    }
+
+    pub fn date(&self) -> String {
+        self.date.clone().unwrap_or_else(utils::today)
+    }
+
+    /// Team ids of the favorite teams.
+    pub fn favorites(&self) -> Result<Vec<&'static str>, Error> {
+        self.favorites
+            .iter()
+            .map(|x| {
+                nil::teams::from_tri_code(x)
+                    .map(|team| team.team_id)
+                    .ok_or_else(|| format!("unknown team `{}`", x).into())
+            })
+            .collect()
+    }
+}
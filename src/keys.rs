@@ -0,0 +1,286 @@
+use crate::app::Tab;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use nil::Error;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+/// Something a key can be bound to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    NextTab,
+    PrevTab,
+    ScrollUp,
+    ScrollDown,
+    ScrollLeft,
+    ScrollRight,
+    NextTeam,
+    PrevTeam,
+    Select,
+    ToggleDivision,
+    NextPeriod,
+    Refresh,
+    Help,
+    Quit,
+}
+
+impl Action {
+    pub const ALL: [Action; 14] = [
+        Action::NextTab,
+        Action::PrevTab,
+        Action::ScrollUp,
+        Action::ScrollDown,
+        Action::ScrollLeft,
+        Action::ScrollRight,
+        Action::NextTeam,
+        Action::PrevTeam,
+        Action::Select,
+        Action::ToggleDivision,
+        Action::NextPeriod,
+        Action::Refresh,
+        Action::Help,
+        Action::Quit,
+    ];
+
+    /// Name used in the config file.
+    pub fn name(self) -> &'static str {
+        match self {
+            Action::NextTab => "next_tab",
+            Action::PrevTab => "prev_tab",
+            Action::ScrollUp => "scroll_up",
+            Action::ScrollDown => "scroll_down",
+            Action::ScrollLeft => "scroll_left",
+            Action::ScrollRight => "scroll_right",
+            Action::NextTeam => "next_team",
+            Action::PrevTeam => "prev_team",
+            Action::Select => "select",
+            Action::ToggleDivision => "toggle_division",
+            Action::NextPeriod => "next_period",
+            Action::Refresh => "refresh",
+            Action::Help => "help",
+            Action::Quit => "quit",
+        }
+    }
+
+    pub fn description(self) -> &'static str {
+        match self {
+            Action::NextTab => "Next tab",
+            Action::PrevTab => "Previous tab",
+            Action::ScrollUp => "Move the selection up",
+            Action::ScrollDown => "Move the selection down",
+            Action::ScrollLeft => "Scroll the columns left",
+            Action::ScrollRight => "Scroll the columns right",
+            Action::NextTeam => "Next team",
+            Action::PrevTeam => "Previous team",
+            Action::Select => "Open the selected game or player",
+            Action::ToggleDivision => "Group by division or conference",
+            Action::NextPeriod => "Switch the period shown",
+            Action::Refresh => "Reload the schedule and the game",
+            Action::Help => "Show this help",
+            Action::Quit => "Quit",
+        }
+    }
+
+    /// Whether the action does anything on the tab `tab`.
+    pub fn is_available(self, tab: Tab) -> bool {
+        match self {
+            Action::ScrollUp | Action::ScrollDown => !matches!(
+                tab,
+                Tab::Standings | Tab::Compare | Tab::Lineups | Tab::Rotation
+            ),
+            Action::ScrollLeft | Action::ScrollRight => tab == Tab::Boxscore,
+            Action::NextTeam | Action::PrevTeam => tab.has_team_tabs() || tab == Tab::Team,
+            Action::Select => matches!(tab, Tab::Boxscore | Tab::Scoreboard | Tab::Team),
+            Action::ToggleDivision => tab == Tab::Standings,
+            Action::NextPeriod => matches!(tab, Tab::Boxscore | Tab::Shots),
+            Action::NextTab | Action::PrevTab | Action::Refresh | Action::Help | Action::Quit => {
+                true
+            }
+        }
+    }
+}
+
+impl FromStr for Action {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Action::ALL
+            .iter()
+            .copied()
+            .find(|x| x.name() == s)
+            .ok_or_else(|| format!("unknown action `{}`", s).into())
+    }
+}
+
+/// A key press, parsed from names like `q`, `H`, `enter` or `shift-left`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Key(KeyEvent);
+
+impl From<KeyEvent> for Key {
+    /// Folds the shift modifier of characters into the character, so that
+    /// `H` and `shift-h` are the same key.
+    fn from(mut event: KeyEvent) -> Self {
+        if let KeyCode::Char(c) = event.code {
+            if event.modifiers.contains(KeyModifiers::SHIFT) {
+                event.code = KeyCode::Char(c.to_ascii_uppercase());
+                event.modifiers.remove(KeyModifiers::SHIFT);
+            }
+        }
+        Key(event)
+    }
+}
+
+impl FromStr for Key {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut modifiers = KeyModifiers::NONE;
+        let mut name = s;
+        loop {
+            let lower = name.to_ascii_lowercase();
+            if lower.starts_with("shift-") {
+                modifiers |= KeyModifiers::SHIFT;
+            } else if lower.starts_with("ctrl-") {
+                modifiers |= KeyModifiers::CONTROL;
+            } else if lower.starts_with("alt-") {
+                modifiers |= KeyModifiers::ALT;
+            } else {
+                break;
+            }
+            name = &name[name.find('-').unwrap() + 1..];
+        }
+        let code = match name.to_ascii_lowercase().as_str() {
+            "left" => KeyCode::Left,
+            "right" => KeyCode::Right,
+            "up" => KeyCode::Up,
+            "down" => KeyCode::Down,
+            "enter" => KeyCode::Enter,
+            "esc" => KeyCode::Esc,
+            "tab" => KeyCode::Tab,
+            "backtab" => KeyCode::BackTab,
+            "backspace" => KeyCode::Backspace,
+            "home" => KeyCode::Home,
+            "end" => KeyCode::End,
+            "pageup" => KeyCode::PageUp,
+            "pagedown" => KeyCode::PageDown,
+            "space" => KeyCode::Char(' '),
+            _ => {
+                let mut chars = name.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => KeyCode::Char(c),
+                    _ => return Err(format!("unknown key `{}`", s).into()),
+                }
+            }
+        };
+        Ok(Key::from(KeyEvent::new(code, modifiers)))
+    }
+}
+
+impl fmt::Display for Key {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let KeyEvent { code, modifiers } = self.0;
+        if modifiers.contains(KeyModifiers::CONTROL) {
+            f.write_str("ctrl-")?;
+        }
+        if modifiers.contains(KeyModifiers::ALT) {
+            f.write_str("alt-")?;
+        }
+        if modifiers.contains(KeyModifiers::SHIFT) {
+            f.write_str("shift-")?;
+        }
+        match code {
+            KeyCode::Left => f.write_str("left"),
+            KeyCode::Right => f.write_str("right"),
+            KeyCode::Up => f.write_str("up"),
+            KeyCode::Down => f.write_str("down"),
+            KeyCode::Enter => f.write_str("enter"),
+            KeyCode::Esc => f.write_str("esc"),
+            KeyCode::Tab => f.write_str("tab"),
+            KeyCode::BackTab => f.write_str("backtab"),
+            KeyCode::Backspace => f.write_str("backspace"),
+            KeyCode::Home => f.write_str("home"),
+            KeyCode::End => f.write_str("end"),
+            KeyCode::PageUp => f.write_str("pageup"),
+            KeyCode::PageDown => f.write_str("pagedown"),
+            KeyCode::Char(' ') => f.write_str("space"),
+            KeyCode::Char(c) => write!(f, "{}", c),
+            _ => write!(f, "{:?}", code),
+        }
+    }
+}
+
+const DEFAULT_BINDINGS: [(&str, Action); 22] = [
+    ("right", Action::NextTab),
+    ("l", Action::NextTab),
+    ("tab", Action::NextTab),
+    ("left", Action::PrevTab),
+    ("h", Action::PrevTab),
+    ("backtab", Action::PrevTab),
+    ("up", Action::ScrollUp),
+    ("k", Action::ScrollUp),
+    ("down", Action::ScrollDown),
+    ("j", Action::ScrollDown),
+    ("<", Action::ScrollLeft),
+    (">", Action::ScrollRight),
+    ("shift-right", Action::NextTeam),
+    ("L", Action::NextTeam),
+    ("shift-left", Action::PrevTeam),
+    ("H", Action::PrevTeam),
+    ("enter", Action::Select),
+    ("d", Action::ToggleDivision),
+    ("p", Action::NextPeriod),
+    ("r", Action::Refresh),
+    ("?", Action::Help),
+    ("q", Action::Quit),
+];
+
+/// Key bindings, the defaults with the overrides of the config file.
+#[derive(Debug)]
+pub struct KeyMap {
+    bindings: Vec<(Key, Action)>,
+}
+
+impl KeyMap {
+    /// Applies `overrides`, a map of key names to action names, to the
+    /// default bindings. The action `none` unbinds a key.
+    pub fn new(overrides: &BTreeMap<String, String>) -> Result<Self, Error> {
+        let mut keys = KeyMap {
+            bindings: Vec::new(),
+        };
+        for &(key, action) in DEFAULT_BINDINGS.iter() {
+            keys.bind(key.parse()?, Some(action));
+        }
+        for (key, action) in overrides {
+            let action = match action.as_str() {
+                "none" => None,
+                action => Some(action.parse()?),
+            };
+            keys.bind(key.parse()?, action);
+        }
+        Ok(keys)
+    }
+
+    fn bind(&mut self, key: Key, action: Option<Action>) {
+        self.bindings.retain(|(x, _)| *x != key);
+        if let Some(action) = action {
+            self.bindings.push((key, action));
+        }
+    }
+
+    pub fn action(&self, event: KeyEvent) -> Option<Action> {
+        let key = Key::from(event);
+        self.bindings
+            .iter()
+            .find(|(x, _)| *x == key)
+            .map(|(_, action)| *action)
+    }
+
+    /// Keys bound to `action`.
+    pub fn keys(&self, action: Action) -> Vec<Key> {
+        self.bindings
+            .iter()
+            .filter(|(_, x)| *x == action)
+            .map(|(key, _)| *key)
+            .collect()
+    }
+}
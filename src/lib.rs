@@ -0,0 +1,39 @@
+//! Parsing of the data.nba.com schedule, boxscore and play by play feeds.
+//!
+//! Every type borrows from the fetched json. `new` and `fetch` leak it for
+//! the lifetime of the program, `from_json` leaves it to the caller.
+//!
+//! Each feed can be loaded with a blocking `new` or an async `fetch`, the
+//! latter needs to run on a tokio runtime.
+#![warn(missing_docs)]
+
+/// Boxscore feed of a game.
+pub mod boxscore;
+/// Playoff series grouped from the schedule.
+pub mod bracket;
+/// Lineups, on/off splits and rotations reconstructed from substitutions.
+pub mod lineups;
+/// Play by play feed of a game and the game flow derived from it.
+pub mod pbp;
+/// Boxscores of a part of a game counted from the play by play.
+pub mod periods;
+/// Season averages from the player profile feed.
+pub mod profile;
+/// Season schedule feed.
+pub mod schedule;
+/// Standings and team schedules computed from the season schedule.
+pub mod standings;
+/// Numeric stats parsed from the boxscore and the metrics derived from them.
+pub mod stats;
+/// Static information about the franchises of the league.
+pub mod teams;
+/// Types shared by the feeds, players and the league roster.
+pub mod utils;
+
+pub use boxscore::{BoxScore, Team};
+pub use pbp::PlayByPlay;
+pub use schedule::Schedule;
+pub use utils::Player;
+
+/// Error returned when fetching or parsing a feed.
+pub type Error = Box<dyn std::error::Error + Send + Sync>;
@@ -0,0 +1,632 @@
+use crate::boxscore::BoxScore;
+use crate::pbp::{self, Action, Play};
+use crate::utils::Player;
+
+/// Minutes, points and estimated possessions while some players were on
+/// the court.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Stint {
+    /// Game minutes played.
+    pub minutes: f32,
+    /// Points scored by the team.
+    pub points_for: u16,
+    /// Points allowed by the team.
+    pub points_against: u16,
+    /// Estimated possessions of the team.
+    pub possessions: f32,
+}
+
+impl Stint {
+    /// Points scored minus points allowed.
+    pub fn plus_minus(&self) -> i32 {
+        self.points_for as i32 - self.points_against as i32
+    }
+
+    /// Point differential per 100 possessions, `None` without any possession.
+    pub fn net_rating(&self) -> Option<f32> {
+        if self.possessions <= 0.0 {
+            return None;
+        }
+        Some(100.0 * self.plus_minus() as f32 / self.possessions)
+    }
+
+    fn add(&mut self, other: &Stint) {
+        self.minutes += other.minutes;
+        self.points_for += other.points_for;
+        self.points_against += other.points_against;
+        self.possessions += other.possessions;
+    }
+
+    fn sub(&self, other: &Stint) -> Stint {
+        Stint {
+            minutes: (self.minutes - other.minutes).max(0.0),
+            points_for: self.points_for.saturating_sub(other.points_for),
+            points_against: self.points_against.saturating_sub(other.points_against),
+            possessions: (self.possessions - other.possessions).max(0.0),
+        }
+    }
+}
+
+/// A unit of players on the court together.
+#[derive(Debug, Clone)]
+pub struct Lineup<'lf> {
+    /// Whether the players are of the home team.
+    pub home: bool,
+    /// Person ids, sorted.
+    pub players: Vec<&'lf str>,
+    /// Totals while the players were on the court together.
+    pub stint: Stint,
+}
+
+/// Splits of a player with them on and off the court.
+#[derive(Debug, Clone)]
+pub struct OnOff<'lf> {
+    /// Person id of the player.
+    pub person_id: &'lf str,
+    /// Whether the players are of the home team.
+    pub home: bool,
+    /// Totals of the team with the player on the court.
+    pub on: Stint,
+    /// Totals of the team with the player on the bench.
+    pub off: Stint,
+}
+
+/// Stretches of the game a player spent on the court.
+#[derive(Debug, Clone)]
+pub struct Rotation<'lf> {
+    /// Person id of the player.
+    pub person_id: &'lf str,
+    /// Whether the players are of the home team.
+    pub home: bool,
+    /// Game minutes elapsed at the start and end of each stretch.
+    pub stints: Vec<(f32, f32)>,
+}
+
+/// Lineups of both teams reconstructed from the substitutions of the play
+/// by play.
+#[derive(Debug, Clone, Default)]
+pub struct Lineups<'lf> {
+    /// Sorted by minutes played.
+    pub lineups: Vec<Lineup<'lf>>,
+    /// On/off splits of every player who played.
+    pub on_off: Vec<OnOff<'lf>>,
+    /// In the boxscore order of the players, starters first.
+    pub rotations: Vec<Rotation<'lf>>,
+    /// Person ids of the players of both teams on the court during each
+    /// play, before its substitutions.
+    pub on_court: Vec<Vec<&'lf str>>,
+}
+
+impl<'lf> Lineups<'lf> {
+    /// Starts from the starters of the boxscore and infers the players on
+    /// the court at the start of each period from who is subbed out first.
+    pub fn new(boxscore: &BoxScore<'lf>, plays: &[Play<'lf>]) -> Self {
+        let teams = [
+            (true, boxscore.h_team.team_id, boxscore.h_team.tri_code),
+            (false, boxscore.v_team.team_id, boxscore.v_team.tri_code),
+        ];
+        let mut lineups = Lineups {
+            on_court: vec![Vec::new(); plays.len()],
+            ..Lineups::default()
+        };
+        for &(home, team_id, tri_code) in teams.iter() {
+            let players: Vec<&Player<'lf>> = boxscore
+                .players
+                .iter()
+                .filter(|x| x.team_id == Some(team_id))
+                .collect();
+            lineups.add_team(home, tri_code, &players, plays);
+        }
+        lineups
+            .lineups
+            .sort_by(|a, b| b.stint.minutes.total_cmp(&a.stint.minutes));
+        lineups
+    }
+
+    fn add_team(
+        &mut self,
+        home: bool,
+        tri_code: &str,
+        players: &[&Player<'lf>],
+        plays: &[Play<'lf>],
+    ) {
+        let elapsed = pbp::elapsed(plays);
+        let possessions = possessions(plays, tri_code);
+        // Substitutions of the team as (play index, leaving, entering)
+        let subs: Vec<(usize, &'lf str, &'lf str)> = plays
+            .iter()
+            .enumerate()
+            .filter(|(_, x)| x.team == Some(tri_code))
+            .filter_map(|(i, x)| {
+                let sub = x.substitution()?;
+                // The person id of a substitution is the player leaving
+                let leaving = x
+                    .person_id
+                    .filter(|&id| {
+                        players
+                            .iter()
+                            .any(|p| p.person_id() == id && sub.leaving.ends_with(p.last_name))
+                    })
+                    .or_else(|| find_player(players, sub.leaving))?;
+                let others: Vec<&Player<'lf>> = players
+                    .iter()
+                    .copied()
+                    .filter(|p| p.person_id() != leaving)
+                    .collect();
+                Some((i, leaving, find_player(&others, sub.entering)?))
+            })
+            .collect();
+
+        let mut on_court: Vec<&'lf str> = players
+            .iter()
+            .filter(|x| x.pos.is_some_and(|x| !x.is_empty()))
+            .map(|x| x.person_id())
+            .take(5)
+            .collect();
+        let mut team_lineups: Vec<Lineup<'lf>> = Vec::new();
+        let mut rotations: Vec<Rotation<'lf>> = players
+            .iter()
+            .map(|x| Rotation {
+                person_id: x.person_id(),
+                home,
+                stints: Vec::new(),
+            })
+            .collect();
+        for (i, play) in plays.iter().enumerate() {
+            let previous = i.checked_sub(1).map(|x| &plays[x]);
+            if previous.is_none_or(|x| x.period != play.period) {
+                on_court = period_starters(&on_court, &subs, plays, i);
+            }
+            self.on_court[i].extend(on_court.iter().copied());
+            if let Some(previous) = previous {
+                let minutes = (elapsed[i] - elapsed[i - 1]).max(0.0);
+                let (h, v) = (
+                    play.h_score.saturating_sub(previous.h_score) as u16,
+                    play.v_score.saturating_sub(previous.v_score) as u16,
+                );
+                let stint = Stint {
+                    minutes,
+                    points_for: if home { h } else { v },
+                    points_against: if home { v } else { h },
+                    possessions: possessions[i],
+                };
+                for rotation in rotations
+                    .iter_mut()
+                    .filter(|x| minutes > 0.0 && on_court.contains(&x.person_id))
+                {
+                    match rotation.stints.last_mut() {
+                        Some(stint) if stint.1 == elapsed[i - 1] => stint.1 = elapsed[i],
+                        _ => rotation.stints.push((elapsed[i - 1], elapsed[i])),
+                    }
+                }
+                let mut players = on_court.clone();
+                players.sort_unstable();
+                match team_lineups.iter_mut().find(|x| x.players == players) {
+                    Some(lineup) => lineup.stint.add(&stint),
+                    None => team_lineups.push(Lineup {
+                        home,
+                        players,
+                        stint,
+                    }),
+                }
+            }
+            for &(_, leaving, entering) in subs.iter().filter(|(x, _, _)| *x == i) {
+                on_court.retain(|x| *x != leaving);
+                if !on_court.contains(&entering) {
+                    on_court.push(entering);
+                }
+            }
+        }
+
+        // Units only seen between plays at the same time
+        team_lineups.retain(|x| x.stint.minutes > 0.0 || x.stint.plus_minus() != 0);
+        let mut total = Stint::default();
+        for lineup in team_lineups.iter() {
+            total.add(&lineup.stint);
+        }
+        for player in players {
+            let person_id = player.person_id();
+            let mut on = Stint::default();
+            for lineup in team_lineups
+                .iter()
+                .filter(|x| x.players.contains(&person_id))
+            {
+                on.add(&lineup.stint);
+            }
+            if on.minutes > 0.0 {
+                self.on_off.push(OnOff {
+                    person_id,
+                    home,
+                    on,
+                    off: total.sub(&on),
+                });
+            }
+        }
+        self.lineups.extend(team_lineups);
+        rotations.retain(|x| !x.stints.is_empty());
+        self.rotations.extend(rotations);
+    }
+
+    /// Lineups of the home or visitor team.
+    pub fn team(&self, home: bool) -> impl Iterator<Item = &Lineup<'lf>> {
+        self.lineups.iter().filter(move |x| x.home == home)
+    }
+}
+
+/// Possessions used by the team `tri_code` at each play: field goal
+/// attempts, turnovers and 0.44 per free throw attempt, minus offensive
+/// rebounds.
+fn possessions(plays: &[Play], tri_code: &str) -> Vec<f32> {
+    // Team of the last missed shot, telling offensive rebounds apart
+    let mut last_miss = None;
+    plays
+        .iter()
+        .map(|play| {
+            let (team, action) = match play.team.zip(Action::parse(play.action())) {
+                Some(x) => x,
+                None => return 0.0,
+            };
+            let offensive = action == Action::Rebound && last_miss == Some(team);
+            match action {
+                Action::FieldGoal { made: false, .. } | Action::FreeThrow { made: false } => {
+                    last_miss = Some(team)
+                }
+                Action::Rebound => last_miss = None,
+                _ => (),
+            }
+            if team != tri_code {
+                return 0.0;
+            }
+            match action {
+                Action::FieldGoal { .. } | Action::Turnover => 1.0,
+                Action::FreeThrow { .. } => 0.44,
+                Action::Rebound if offensive => -1.0,
+                _ => 0.0,
+            }
+        })
+        .collect()
+}
+
+/// Players on the court at the play `start` opening a period: the ones
+/// subbed out before being subbed in, then the ones of the last period that
+/// are not subbed in first.
+fn period_starters<'lf>(
+    last: &[&'lf str],
+    subs: &[(usize, &'lf str, &'lf str)],
+    plays: &[Play],
+    start: usize,
+) -> Vec<&'lf str> {
+    let period = plays[start].period;
+    let end = plays[start..]
+        .iter()
+        .position(|x| x.period != period)
+        .map_or(plays.len(), |x| start + x);
+    let mut starters = Vec::new();
+    let mut entered = Vec::new();
+    for &(_, leaving, entering) in subs.iter().filter(|(i, _, _)| (start..end).contains(i)) {
+        if !entered.contains(&leaving) && !starters.contains(&leaving) {
+            starters.push(leaving);
+        }
+        entered.push(entering);
+    }
+    for player in last {
+        if starters.len() < 5 && !entered.contains(player) && !starters.contains(player) {
+            starters.push(player);
+        }
+    }
+    starters
+}
+
+/// Person id of the player named `name` in the play by play, usually only
+/// the last name. `None` when several players match, like teammates sharing
+/// a last name.
+pub(crate) fn find_player<'lf>(players: &[&Player<'lf>], name: &str) -> Option<&'lf str> {
+    let mut matches = players
+        .iter()
+        .filter(|x| x.last_name == name || format!("{} {}", x.first_name, x.last_name) == name);
+    let player = matches.next()?;
+    if matches.next().is_some() {
+        return None;
+    }
+    Some(player.person_id())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn player(person_id: &str, first_name: &str, last_name: &str) -> Player<'static> {
+        let json = format!(
+            r#"{{"personId":"{}","firstName":"{}","lastName":"{}","teamId":"1"}}"#,
+            person_id, first_name, last_name
+        );
+        serde_json::from_str(Box::leak(json.into_boxed_str())).unwrap()
+    }
+
+    fn play(period: u8, description: &'static str) -> Play<'static> {
+        Play {
+            clock: "12:00",
+            description,
+            h_score: 0,
+            v_score: 0,
+            period,
+            team: Some("PHI"),
+            person_id: None,
+            shot: None,
+        }
+    }
+
+    #[test]
+    fn substitution_between_namesakes() {
+        let json = r#"{"basicGameData":{"clock":"","period":{"current":1,"type":0,"maxRegular":4},
+            "vTeam":{"teamId":"2","triCode":"BOS","win":"0","loss":"0","score":"0","linescore":[]},
+            "hTeam":{"teamId":"1","triCode":"PHI","win":"0","loss":"0","score":"0","linescore":[]}}}"#;
+        let mut boxscore: BoxScore = serde_json::from_str(json).unwrap();
+        let starter = |person_id: &str, last_name: &str| {
+            let json = format!(
+                r#"{{"personId":"{}","firstName":"X","lastName":"{}","teamId":"1","pos":"F"}}"#,
+                person_id, last_name
+            );
+            serde_json::from_str::<Player>(Box::leak(json.into_boxed_str())).unwrap()
+        };
+        boxscore.players = vec![
+            starter("1", "Morris"),
+            starter("3", "Harris"),
+            starter("4", "Embiid"),
+            starter("5", "Curry"),
+            starter("6", "Simmons"),
+            player("2", "Markieff", "Morris"),
+        ];
+        let mut sub = play(1, "[PHI] Morris Substitution replaced by Morris");
+        sub.clock = "6:00";
+        sub.person_id = Some("1");
+        let mut end = play(1, "End Period");
+        end.clock = "0:00";
+        let plays = [play(1, "Start Period"), sub, end];
+        let lineups = Lineups::new(&boxscore, &plays);
+        let rotation = |person_id| {
+            lineups
+                .rotations
+                .iter()
+                .find(|x| x.person_id == person_id)
+                .map(|x| x.stints.clone())
+        };
+        assert_eq!(rotation("1"), Some(vec![(0.0, 6.0)]));
+        assert_eq!(rotation("2"), Some(vec![(6.0, 12.0)]));
+    }
+
+    #[test]
+    fn possessions_from_plays() {
+        let mut turnover = play(
+            1,
+            "[BOS] Smart Turnover : Bad Pass (1 TO) Steal:Curry (1 ST)",
+        );
+        turnover.team = Some("BOS");
+        let mut rebound = play(1, "[BOS] Tatum Rebound (Off:0 Def:1)");
+        rebound.team = Some("BOS");
+        let plays = [
+            play(1, "[PHI] Harris Jump Shot: Missed"),
+            play(1, "[PHI] Embiid Rebound (Off:1 Def:0)"),
+            play(1, "[PHI 2-0] Embiid Layup Shot: Made (2 PTS)"),
+            turnover,
+            play(1, "[PHI] Curry 3pt Shot: Missed"),
+            rebound,
+            play(1, "[PHI] Simmons Foul: Personal (1 PF)"),
+            play(1, "[PHI 3-0] Embiid Free Throw 1 of 2 Made (3 PTS)"),
+            play(1, "[PHI] Embiid Free Throw 2 of 2 Missed"),
+        ];
+        assert_eq!(
+            possessions(&plays, "PHI"),
+            vec![1.0, -1.0, 1.0, 0.0, 1.0, 0.0, 0.0, 0.44, 0.44]
+        );
+        assert_eq!(
+            possessions(&plays, "BOS"),
+            vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0]
+        );
+    }
+
+    #[test]
+    fn find_player_shared_last_name() {
+        let players = [
+            player("1", "Marcus", "Morris"),
+            player("2", "Markieff", "Morris"),
+            player("3", "Jrue", "Holiday"),
+        ];
+        let players: Vec<&Player> = players.iter().collect();
+        assert_eq!(find_player(&players, "Holiday"), Some("3"));
+        assert_eq!(find_player(&players, "Morris"), None);
+        assert_eq!(find_player(&players, "Markieff Morris"), Some("2"));
+        assert_eq!(find_player(&players[1..], "Morris"), Some("2"));
+        assert_eq!(find_player(&players, "Smith"), None);
+    }
+
+    #[test]
+    fn period_starters_from_substitutions() {
+        let plays = [
+            play(1, "Start Period"),
+            play(1, "End Period"),
+            play(2, "Start Period"),
+            play(2, "[PHI] A Substitution replaced by F"),
+            play(2, "[PHI] F Substitution replaced by G"),
+            play(2, "[PHI] H Substitution replaced by B"),
+            play(3, "Start Period"),
+        ];
+        let subs = [(3, "a", "f"), (4, "f", "g"), (5, "h", "b")];
+        let last = ["a", "b", "c", "d", "e"];
+        // A and H were subbed out first, B came back in and F only played
+        // in the period
+        assert_eq!(
+            period_starters(&last, &subs, &plays, 2),
+            vec!["a", "h", "c", "d", "e"]
+        );
+        // Without substitutions the last players stay on the court
+        assert_eq!(period_starters(&last, &subs, &plays, 6), last.to_vec());
+    }
+
+    /// Boxscore of PHI at home against BOS, the first five players of each
+    /// team starting and Thybulle on the PHI bench.
+    fn game() -> BoxScore<'static> {
+        let json = r#"{"basicGameData":{"clock":"","period":{"current":2,"type":0,"maxRegular":4},
+            "vTeam":{"teamId":"2","triCode":"BOS","win":"0","loss":"0","score":"3","linescore":[]},
+            "hTeam":{"teamId":"1","triCode":"PHI","win":"0","loss":"0","score":"2","linescore":[]}}}"#;
+        let mut boxscore: BoxScore = serde_json::from_str(json).unwrap();
+        let players = [
+            ("1", "Embiid", "1", "C"),
+            ("3", "Harris", "1", "F"),
+            ("5", "Curry", "1", "G"),
+            ("6", "Simmons", "1", "G"),
+            ("7", "Green", "1", "G"),
+            ("8", "Thybulle", "1", ""),
+            ("11", "Tatum", "2", "F"),
+            ("12", "Brown", "2", "G"),
+            ("13", "Smart", "2", "G"),
+            ("14", "Walker", "2", "G"),
+            ("15", "Theis", "2", "C"),
+        ];
+        boxscore.players = players
+            .iter()
+            .map(|(person_id, last_name, team_id, pos)| {
+                let json = format!(
+                    r#"{{"personId":"{}","firstName":"X","lastName":"{}","teamId":"{}","pos":"{}"}}"#,
+                    person_id, last_name, team_id, pos
+                );
+                serde_json::from_str(Box::leak(json.into_boxed_str())).unwrap()
+            })
+            .collect();
+        boxscore
+    }
+
+    /// A play as the feed sends it, `team` being empty for game events.
+    fn feed_play(
+        period: &str,
+        clock: &str,
+        (h_score, v_score): (u8, u8),
+        team: &str,
+        description: &str,
+    ) -> Play<'static> {
+        let json = format!(
+            r#"{{"clock":"{}","description":"{}","home_score":"{}","visitor_score":"{}","period":"{}","team_abr":"{}"}}"#,
+            clock, description, h_score, v_score, period, team
+        );
+        serde_json::from_str(Box::leak(json.into_boxed_str())).unwrap()
+    }
+
+    /// Harris goes to the bench for Thybulle midway through the first
+    /// quarter, BOS plays its starters throughout.
+    fn game_plays() -> Vec<Play<'static>> {
+        vec![
+            feed_play("1", "12:00", (0, 0), "", "Start Period"),
+            feed_play(
+                "1",
+                "10:00",
+                (2, 0),
+                "PHI",
+                "[PHI 2-0] Embiid Layup Shot: Made (2 PTS)",
+            ),
+            feed_play(
+                "1",
+                "6:00",
+                (2, 0),
+                "PHI",
+                "[PHI] Harris Substitution replaced by Thybulle",
+            ),
+            feed_play(
+                "1",
+                "3:00",
+                (2, 3),
+                "BOS",
+                "[BOS 3-2] Tatum 3pt Shot: Made (3 PTS)",
+            ),
+            feed_play("1", "0:00", (2, 3), "", "End Period"),
+            feed_play("2", "12:00", (2, 3), "", "Start Period"),
+            feed_play("2", "0:00", (2, 3), "", "End Period"),
+        ]
+    }
+
+    #[test]
+    fn lineup_minutes_and_points() {
+        let plays = game_plays();
+        let lineups = Lineups::new(&game(), &plays);
+        let units: Vec<(bool, f32, u16, u16)> = lineups
+            .lineups
+            .iter()
+            .map(|x| {
+                (
+                    x.home,
+                    x.stint.minutes,
+                    x.stint.points_for,
+                    x.stint.points_against,
+                )
+            })
+            .collect();
+        assert_eq!(
+            units,
+            vec![(false, 24.0, 3, 2), (true, 18.0, 0, 3), (true, 6.0, 2, 0)]
+        );
+        let bench_unit = &lineups.lineups[1];
+        assert!(bench_unit.players.contains(&"8"));
+        assert!(!bench_unit.players.contains(&"3"));
+        assert_eq!(bench_unit.stint.plus_minus(), -3);
+        // One field goal attempt each for BOS and the PHI starters
+        let ratings: Vec<(f32, Option<f32>)> = lineups
+            .lineups
+            .iter()
+            .map(|x| (x.stint.possessions, x.stint.net_rating()))
+            .collect();
+        assert_eq!(
+            ratings,
+            vec![(1.0, Some(100.0)), (0.0, None), (1.0, Some(200.0))]
+        );
+    }
+
+    #[test]
+    fn net_rating() {
+        let stint = Stint {
+            minutes: 12.0,
+            points_for: 30,
+            points_against: 25,
+            possessions: 25.0,
+        };
+        assert_eq!(stint.net_rating(), Some(20.0));
+        assert_eq!(Stint::default().net_rating(), None);
+    }
+
+    #[test]
+    fn on_off_splits() {
+        let plays = game_plays();
+        let lineups = Lineups::new(&game(), &plays);
+        let split = |person_id| {
+            let x = lineups
+                .on_off
+                .iter()
+                .find(|x| x.person_id == person_id)
+                .unwrap();
+            (
+                (x.on.minutes, x.on.plus_minus()),
+                (x.off.minutes, x.off.plus_minus()),
+            )
+        };
+        assert_eq!(split("3"), ((6.0, 2), (18.0, -3)));
+        assert_eq!(split("8"), ((18.0, -3), (6.0, 2)));
+        assert_eq!(split("1"), ((24.0, -1), (0.0, 0)));
+        assert_eq!(split("11"), ((24.0, 1), (0.0, 0)));
+    }
+
+    #[test]
+    fn rotation_stints() {
+        let plays = game_plays();
+        let lineups = Lineups::new(&game(), &plays);
+        let rotation = |person_id| {
+            lineups
+                .rotations
+                .iter()
+                .find(|x| x.person_id == person_id)
+                .map(|x| x.stints.clone())
+        };
+        assert_eq!(rotation("1"), Some(vec![(0.0, 24.0)]));
+        assert_eq!(rotation("3"), Some(vec![(0.0, 6.0)]));
+        // Thybulle stays on through the end of the first quarter
+        assert_eq!(rotation("8"), Some(vec![(6.0, 24.0)]));
+        assert_eq!(rotation("11"), Some(vec![(0.0, 24.0)]));
+    }
+}
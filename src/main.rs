@@ -1,19 +1,19 @@
 mod app;
-mod boxscore;
-mod pbp;
-mod schedule;
+mod cli;
+mod columns;
+mod config;
+mod keys;
+mod network;
+mod theme;
 mod ui;
-mod utils;
-use clap::{App, AppSettings, Arg};
+use clap::{App, AppSettings, Arg, SubCommand};
 use crossterm::{
-    event::{
-        self, DisableMouseCapture, EnableMouseCapture, Event as CEvent, KeyCode, KeyModifiers,
-    },
+    event::{self, DisableMouseCapture, EnableMouseCapture, Event as CEvent},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
+use keys::Action;
 use std::{
-    error::Error,
     io::stdout,
     sync::mpsc,
     thread,
@@ -23,54 +23,119 @@ use tui::{backend::CrosstermBackend, Terminal};
 
 static VERSION: &str = "0.1";
 
-enum Event<I, J> {
+pub enum Event<I, J> {
     Input(I),
-    MouseEvent(J),
+    Mouse(J),
     Tick,
+    Update(network::Update),
 }
 
-fn main() -> Result<(), Box<dyn Error>> {
+fn main() -> Result<(), nil::Error> {
     let matches = App::new("NBAScores")
         .version(VERSION)
         .author("Devin S. <drsingh2518@icloud.com>")
         .about("Get NBA scores")
         .args(&[
             Arg::with_name("enhanced_graphics")
-                .long("enchanced_graphics")
+                .long("enhanced_graphics")
+                .alias("enchanced_graphics")
                 .short("e")
                 .takes_value(true)
-                .default_value("true")
                 .possible_values(&["true", "false"])
-                .help("Use nerd font glyphs."),
+                .help("Use nerd font glyphs. Defaults to true"),
             Arg::with_name("tick_rate")
                 .long("tick_rate")
                 .short("t")
                 .takes_value(true)
-                .default_value("250")
-                .help("Tick rate of the ui."),
+                .help("Tick rate of the ui in milliseconds. Defaults to 250"),
             Arg::with_name("date")
                 .short("d")
                 .long("date")
                 .takes_value(true)
                 .help("Choose a date in yyyymmdd format. Defaults to today"),
+            Arg::with_name("refresh")
+                .long("refresh")
+                .short("r")
+                .takes_value(true)
+                .help("Seconds between refreshes of the current game. Defaults to 30"),
+            Arg::with_name("timezone")
+                .long("timezone")
+                .short("z")
+                .takes_value(true)
+                .help("Timezone of displayed times, `local` or a tz database name like `Europe/Paris`. Defaults to local"),
+            Arg::with_name("favorites")
+                .long("favorites")
+                .short("f")
+                .takes_value(true)
+                .use_delimiter(true)
+                .help("Comma separated tri codes of favorite teams, like `BOS,LAL`."),
         ])
+        .subcommand(
+            SubCommand::with_name("standings")
+                .about("Print the standings computed from the season schedule")
+                .arg(
+                    Arg::with_name("division")
+                        .long("division")
+                        .help("Group teams by division instead of conference."),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("export")
+                .about("Print the boxscore, lineups or on/off splits of a game of the date as csv")
+                .args(&[
+                    Arg::with_name("game")
+                        .help("Game id or tri code of one of its teams. Defaults to the first game"),
+                    Arg::with_name("columns")
+                        .long("columns")
+                        .short("c")
+                        .takes_value(true)
+                        .use_delimiter(true)
+                        .help("Comma separated boxscore columns. Defaults to every column"),
+                    Arg::with_name("lineups")
+                        .long("lineups")
+                        .conflicts_with_all(&["columns", "on_off"])
+                        .help("Print the lineups reconstructed from the substitutions"),
+                    Arg::with_name("on_off")
+                        .long("on-off")
+                        .conflicts_with("columns")
+                        .help("Print the on/off splits of every player"),
+                ]),
+        )
+        .subcommand(
+            SubCommand::with_name("config")
+                .about("Print the effective configuration, the config file merged with the flags"),
+        )
         .setting(AppSettings::ColoredHelp)
         .get_matches();
 
-    let enhanced_graphics = matches
-        .value_of("enhanced_graphics")
-        .unwrap()
-        .parse()
-        .unwrap();
+    if let Some(matches) = matches.subcommand_matches("standings") {
+        return cli::standings(matches.is_present("division"));
+    }
 
-    let client = reqwest::blocking::Client::new();
-    let sc = schedule::Schedule::new(&client)
-        .expect("Error occured fetching `http://data.nba.com/prod/v1/2020/schedule.json`.");
-    let date = matches
-        .value_of("date")
-        .unwrap_or(&utils::today())
-        .to_string();
-    let games = sc.get_date_game_id(&date);
+    let mut config = config::Config::load()?;
+    config.merge(&matches)?;
+    if matches.subcommand_matches("config").is_some() {
+        return cli::config(&config);
+    }
+    if let Some(matches) = matches.subcommand_matches("export") {
+        let export = if matches.is_present("lineups") {
+            cli::Export::Lineups
+        } else if matches.is_present("on_off") {
+            cli::Export::OnOff
+        } else {
+            cli::Export::Boxscore(
+                matches
+                    .values_of("columns")
+                    .map(|x| x.map(str::to_string).collect())
+                    .unwrap_or_default(),
+            )
+        };
+        return cli::export(&config, matches.value_of("game"), export);
+    }
+
+    let (tx, rx) = mpsc::channel();
+    let network = network::spawn(tx.clone());
+    let mut app = app::App::new("NBAScores", &config, network)?;
 
     enable_raw_mode()?;
 
@@ -82,9 +147,7 @@ fn main() -> Result<(), Box<dyn Error>> {
     let mut terminal = Terminal::new(backend)?;
 
     // Setup input handling
-    let (tx, rx) = mpsc::channel();
-
-    let tick_rate = Duration::from_millis(matches.value_of("tick_rate").unwrap().parse().unwrap());
+    let tick_rate = Duration::from_millis(config.tick_rate);
     thread::spawn(move || {
         let mut last_tick = Instant::now();
         loop {
@@ -95,9 +158,7 @@ fn main() -> Result<(), Box<dyn Error>> {
             if event::poll(timeout).unwrap() {
                 match event::read() {
                     Ok(CEvent::Key(key)) => tx.send(Event::Input(key)).unwrap(),
-                    Ok(CEvent::Mouse(mouse_event)) => {
-                        tx.send(Event::MouseEvent(mouse_event)).unwrap()
-                    }
+                    Ok(CEvent::Mouse(mouse_event)) => tx.send(Event::Mouse(mouse_event)).unwrap(),
                     _ => (),
                 }
             }
@@ -111,75 +172,30 @@ fn main() -> Result<(), Box<dyn Error>> {
 
     terminal.clear()?;
 
-    if games.is_empty() {
-        loop {
-            terminal.draw(|f| ui::draw_empty_games(f))?;
-            match rx.recv()? {
-                Event::Input(event) => match event.code {
-                    KeyCode::Char('q') => {
-                        disable_raw_mode()?;
-                        execute!(
-                            terminal.backend_mut(),
-                            LeaveAlternateScreen,
-                            DisableMouseCapture
-                        )?;
-                        terminal.show_cursor()?;
-                        break;
-                    }
-                    _ => (),
-                },
-                _ => (),
-            }
-        }
-    } else {
-        let boxscore = boxscore::BoxScore::new(&client, &date, games[0]).unwrap_or_else(|_| {
-            panic!(
-                "Error occured fetching `http://data.nba.com/prod/v1/{}/{}_boxscore.json`",
-                date, games[0],
-            )
-        });
-        let pbp = pbp::PlayByPlay::new(&client, &date, games[0]).unwrap_or_else(|_| {
-            panic!("Error occured fetching `http://data.nba.com/data/10s/json/cms/noseason/game/{}/{}/pbp_all.json`",
-            date, games[0])});
-
-        let mut app = app::App::new("NBAScores", enhanced_graphics, boxscore, pbp, client);
-
-        loop {
-            terminal.draw(|f| ui::draw(f, &mut app))?;
-            match rx.recv()? {
-                Event::Input(event) => match event.modifiers.intersects(KeyModifiers::SHIFT) {
-                    true => match event.code {
-                        KeyCode::Left
-                        | KeyCode::Char('L')
-                        | KeyCode::Right
-                        | KeyCode::Char('H') => app.next_team(),
-                        _ => {}
-                    },
-                    false => match event.code {
-                        KeyCode::Char('q') => {
-                            disable_raw_mode()?;
-                            execute!(
-                                terminal.backend_mut(),
-                                LeaveAlternateScreen,
-                                DisableMouseCapture
-                            )?;
-                            terminal.show_cursor()?;
-                            break;
-                        }
-                        KeyCode::Left | KeyCode::Char('l') => app.on_left(),
-                        KeyCode::Up | KeyCode::Char('k') => app.on_up(),
-                        KeyCode::Right | KeyCode::Char('h') => app.on_right(),
-                        KeyCode::Down | KeyCode::Char('j') => app.on_down(),
-                        KeyCode::Char(c) => app.on_key(c),
-                        _ => {}
-                    },
-                },
-                // TODO
-                Event::MouseEvent(event) => match event.kind {
-                    _ => {}
-                },
-                Event::Tick => app.on_tick(),
-            }
+    loop {
+        terminal.draw(|f| ui::draw(f, &mut app))?;
+        match rx.recv()? {
+            Event::Input(event) => match app.keys.action(event) {
+                Some(Action::Quit) => {
+                    disable_raw_mode()?;
+                    execute!(
+                        terminal.backend_mut(),
+                        LeaveAlternateScreen,
+                        DisableMouseCapture
+                    )?;
+                    terminal.show_cursor()?;
+                    break;
+                }
+                _ if app.show_help || app.show_player => {
+                    app.show_help = false;
+                    app.show_player = false;
+                }
+                Some(action) => app.on_action(action),
+                None => {}
+            },
+            Event::Mouse(event) => app.on_mouse(event),
+            Event::Tick => app.on_tick(),
+            Event::Update(update) => app.on_update(update),
         }
     }
 
@@ -0,0 +1,270 @@
+use crate::Event;
+use nil::{
+    boxscore::BoxScore,
+    pbp::PlayByPlay,
+    profile::SeasonAverages,
+    schedule::{Schedule, Scoreboard},
+    utils::Player,
+    Error,
+};
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+use tokio::sync::mpsc::{unbounded_channel, UnboundedSender};
+
+/// A fetch to run. Schedule and game requests are numbered by `seq`, which
+/// their updates carry back so that the responses of older requests can be
+/// dropped.
+pub enum Request {
+    Schedule {
+        seq: u64,
+        /// Json of the schedule held by the app, the schedule is only sent
+        /// again when the feed returns something else.
+        schedule: Option<Json>,
+    },
+    /// Games of `date` from the scoreboard feed, fresher than the schedule.
+    Scoreboard {
+        seq: u64,
+        date: String,
+        /// Json of the scoreboard held by the app, see `Schedule`.
+        scoreboard: Option<Json>,
+    },
+    Game {
+        seq: u64,
+        date: String,
+        game_id: String,
+        /// Json of the boxscore and the play by play of the game held by
+        /// the app, which are only sent again when they change.
+        boxscore: Option<Json>,
+        plays: Option<Json>,
+    },
+    Profile {
+        person_id: String,
+    },
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Resource {
+    Schedule,
+    Scoreboard,
+    BoxScore,
+    PlayByPlay,
+    Roster,
+}
+
+impl Resource {
+    pub const ALL: [Resource; 5] = [
+        Resource::Schedule,
+        Resource::Scoreboard,
+        Resource::BoxScore,
+        Resource::PlayByPlay,
+        Resource::Roster,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Resource::Schedule => "Schedule",
+            Resource::Scoreboard => "Scoreboard",
+            Resource::BoxScore => "Boxscore",
+            Resource::PlayByPlay => "Play by play",
+            Resource::Roster => "Roster",
+        }
+    }
+}
+
+/// Json of a feed, leaked for the data parsed from it to borrow it as
+/// `'static`. A feed is only leaked again when its json changes.
+pub type Json = &'static str;
+
+/// Time after which a request is given up.
+const TIMEOUT: Duration = Duration::from_secs(20);
+
+/// Result of a request, with the `seq` of the request but for profiles.
+pub enum Update {
+    Loading(u64, Resource),
+    Failed(u64, Resource, Error),
+    Schedule(u64, Json, Schedule<'static>),
+    Scoreboard(u64, Json, Scoreboard<'static>),
+    BoxScore(u64, String, Json, Box<BoxScore<'static>>),
+    PlayByPlay(u64, String, Json, PlayByPlay<'static>),
+    /// The feed returned the json held by the app.
+    Unchanged(u64, Resource),
+    /// Season averages of a player, `None` when the fetch failed.
+    Profile(String, Option<SeasonAverages>),
+}
+
+/// Parses `json`, unless it is the json `held` by the app. The parsed data
+/// borrows the returned json.
+fn parse<T>(
+    json: String,
+    held: Option<Json>,
+    from_json: fn(&'static str) -> Result<T, Error>,
+) -> Result<Option<(Json, T)>, Error> {
+    if held == Some(json.as_str()) {
+        return Ok(None);
+    }
+    let json: Json = Box::leak(json.into_boxed_str());
+    Ok(Some((json, from_json(json)?)))
+}
+
+/// The league roster, fetched once.
+#[derive(Default)]
+struct Cache {
+    roster: Mutex<Option<Vec<Player<'static>>>>,
+}
+
+impl Cache {
+    async fn roster(&self, client: &reqwest::Client) -> Result<Vec<Player<'static>>, Error> {
+        if let Some(roster) = self.roster.lock().unwrap().as_ref() {
+            return Ok(roster.clone());
+        }
+        let roster = Player::fetch_roster(client).await?;
+        *self.roster.lock().unwrap() = Some(roster.clone());
+        Ok(roster)
+    }
+}
+
+/// Spawns the thread running the fetches. Every request is fetched
+/// concurrently and its results are sent back as `Event::Update`s.
+pub fn spawn<I, J>(tx: mpsc::Sender<Event<I, J>>) -> UnboundedSender<Request>
+where
+    I: Send + 'static,
+    J: Send + 'static,
+{
+    let (req_tx, mut req_rx) = unbounded_channel();
+
+    thread::spawn(move || {
+        let rt = tokio::runtime::Runtime::new().expect("Could not start the tokio runtime");
+        rt.block_on(async move {
+            let client = reqwest::Client::builder()
+                .timeout(TIMEOUT)
+                .build()
+                .expect("Could not build the http client");
+            let cache = Arc::new(Cache::default());
+            while let Some(request) = req_rx.recv().await {
+                match request {
+                    Request::Schedule { seq, schedule } => {
+                        let client = client.clone();
+                        let send = sender(&tx);
+                        tokio::spawn(async move {
+                            let schedule = match Schedule::fetch_json(&client).await {
+                                Ok(json) => parse(json, schedule, Schedule::from_json),
+                                Err(e) => Err(e),
+                            };
+                            send(match schedule {
+                                Ok(Some((json, x))) => Update::Schedule(seq, json, x),
+                                Ok(None) => Update::Unchanged(seq, Resource::Schedule),
+                                Err(e) => Update::Failed(seq, Resource::Schedule, e),
+                            });
+                        });
+                    }
+                    Request::Scoreboard {
+                        seq,
+                        date,
+                        scoreboard,
+                    } => {
+                        let client = client.clone();
+                        let send = sender(&tx);
+                        tokio::spawn(async move {
+                            let board = match Scoreboard::fetch_json(&client, &date).await {
+                                Ok(json) => parse(json, scoreboard, Scoreboard::from_json),
+                                Err(e) => Err(e),
+                            };
+                            send(match board {
+                                Ok(Some((json, x))) => Update::Scoreboard(seq, json, x),
+                                Ok(None) => Update::Unchanged(seq, Resource::Scoreboard),
+                                Err(e) => Update::Failed(seq, Resource::Scoreboard, e),
+                            });
+                        });
+                    }
+                    Request::Game {
+                        seq,
+                        date,
+                        game_id,
+                        boxscore,
+                        plays,
+                    } => {
+                        {
+                            let client = client.clone();
+                            let send = sender(&tx);
+                            let cache = cache.clone();
+                            let (date, game_id) = (date.clone(), game_id.clone());
+                            tokio::spawn(async move {
+                                send(
+                                    fetch_boxscore(
+                                        &client, &cache, seq, &date, &game_id, boxscore, &send,
+                                    )
+                                    .await,
+                                );
+                            });
+                        }
+                        let client = client.clone();
+                        let send = sender(&tx);
+                        tokio::spawn(async move {
+                            let pbp = match PlayByPlay::fetch_json(&client, &date, &game_id).await {
+                                Ok(json) => parse(json, plays, PlayByPlay::from_json),
+                                Err(e) => Err(e),
+                            };
+                            send(match pbp {
+                                Ok(Some((json, x))) => Update::PlayByPlay(seq, game_id, json, x),
+                                Ok(None) => Update::Unchanged(seq, Resource::PlayByPlay),
+                                Err(e) => Update::Failed(seq, Resource::PlayByPlay, e),
+                            });
+                        });
+                    }
+                    Request::Profile { person_id } => {
+                        let client = client.clone();
+                        let send = sender(&tx);
+                        tokio::spawn(async move {
+                            let profile = SeasonAverages::fetch(&client, &person_id).await.ok();
+                            send(Update::Profile(person_id, profile));
+                        });
+                    }
+                }
+            }
+        });
+    });
+
+    req_tx
+}
+
+fn sender<I, J>(tx: &mpsc::Sender<Event<I, J>>) -> impl Fn(Update)
+where
+    I: Send + 'static,
+    J: Send + 'static,
+{
+    let tx = tx.clone();
+    move |update| {
+        let _ = tx.send(Event::Update(update));
+    }
+}
+
+/// Fetches the boxscore, then the roster when the game has not started.
+async fn fetch_boxscore(
+    client: &reqwest::Client,
+    cache: &Cache,
+    seq: u64,
+    date: &str,
+    game_id: &str,
+    held: Option<Json>,
+    send: &impl Fn(Update),
+) -> Update {
+    let boxscore = match BoxScore::fetch_json(client, date, game_id).await {
+        Ok(json) => parse(json, held, BoxScore::from_json),
+        Err(e) => Err(e),
+    };
+    let (json, mut boxscore) = match boxscore {
+        Ok(Some(x)) => x,
+        Ok(None) => return Update::Unchanged(seq, Resource::BoxScore),
+        Err(e) => return Update::Failed(seq, Resource::BoxScore, e),
+    };
+    if boxscore.needs_roster() {
+        send(Update::Loading(seq, Resource::Roster));
+        match cache.roster(client).await {
+            Ok(roster) => boxscore.set_roster(&roster),
+            Err(e) => return Update::Failed(seq, Resource::Roster, e),
+        }
+    }
+
+    Update::BoxScore(seq, game_id.to_string(), json, Box::new(boxscore))
+}
@@ -1,39 +1,71 @@
+use crate::{stats, Error};
 use serde::de::Deserialize;
 use serde_derive::Deserialize;
+use std::cmp::Ordering;
 use std::fmt;
 
+/// Play by play of a single game, in chronological order.
 #[derive(Debug)]
 pub struct PlayByPlay<'lf> {
+    /// Every play of the game.
     pub plays: Vec<Play<'lf>>,
 }
 
+fn url(game_date: &str, game_id: &str) -> String {
+    format!(
+        "http://data.nba.com/data/10s/json/cms/noseason/game/{}/{}/pbp_all.json",
+        game_date, game_id
+    )
+}
+
 impl<'lf> PlayByPlay<'lf> {
+    /// Fetches the play by play, blocking version of `fetch`.
     pub fn new(
         client: &reqwest::blocking::Client,
         game_date: &str,
         game_id: &str,
-    ) -> Result<Self, Box<dyn std::error::Error>> {
+    ) -> Result<Self, Error> {
         let json = Box::leak::<'lf>(Box::new(
-            client
-                .get(format!(
-                    "http://data.nba.com/data/10s/json/cms/noseason/game/{}/{}/pbp_all.json",
-                    game_date, game_id
-                ))
-                .send()?
-                .text()?,
+            client.get(url(game_date, game_id)).send()?.text()?,
         ));
-        // let json = Box::leak::<'lf>(Box::new(
-        //     std::fs::read_to_string(
-        //         std::path::PathBuf::from(format!("{}", std::env!("CARGO_MANIFEST_DIR")))
-        //             .join("src/pbp.json"),
-        //     )
-        //     .unwrap(),
-        // ));
 
         let pbp = serde_json::from_str::<PlayByPlay<'lf>>(json)?;
 
         Ok(pbp)
     }
+
+    /// Fetches the play by play.
+    pub async fn fetch(
+        client: &reqwest::Client,
+        game_date: &str,
+        game_id: &str,
+    ) -> Result<Self, Error> {
+        let json = Box::leak::<'lf>(Box::new(
+            Self::fetch_json(client, game_date, game_id).await?,
+        ));
+
+        Self::from_json(json)
+    }
+
+    /// Fetches the json of the play by play feed, to be parsed by
+    /// `from_json`.
+    pub async fn fetch_json(
+        client: &reqwest::Client,
+        game_date: &str,
+        game_id: &str,
+    ) -> Result<String, Error> {
+        Ok(client
+            .get(url(game_date, game_id))
+            .send()
+            .await?
+            .text()
+            .await?)
+    }
+
+    /// Parses the json of the play by play feed.
+    pub fn from_json(json: &'lf str) -> Result<Self, Error> {
+        Ok(serde_json::from_str::<PlayByPlay<'lf>>(json)?)
+    }
 }
 
 impl<'lf, 'de> Deserialize<'de> for PlayByPlay<'lf>
@@ -61,23 +93,112 @@ where
         }
 
         let helper = Root::deserialize(deserializer)?;
-        let plays = if let Some(x) = helper.sports_content.game.play {
-            x
-        } else {
-            Vec::new()
-        };
+        let plays = helper.sports_content.game.play.unwrap_or_default();
 
         Ok(Self { plays })
     }
 }
 
+/// A single play. Scores are the running score after the play.
 #[derive(Debug)]
 pub struct Play<'lf> {
+    /// Game clock of the period when the play happened, as `mm:ss`.
     pub clock: &'lf str,
+    /// Description of the play, e.g.
+    /// `[LAL 2-0] James Layup Shot: Made (2 PTS)`.
     pub description: &'lf str,
+    /// Points of the home team.
     pub h_score: u8,
+    /// Points of the visiting team.
     pub v_score: u8,
-    pub period: Period,
+    /// Period of the play, from `1`, overtimes being `5` and up.
+    pub period: u8,
+    /// Tri code of the team involved, `None` for game events.
+    pub team: Option<&'lf str>,
+    /// Person id of the player involved.
+    pub person_id: Option<&'lf str>,
+    /// Location of a field goal attempt, `None` for other plays.
+    pub shot: Option<Shot>,
+}
+
+impl<'lf> Play<'lf> {
+    /// Whether the score changed since the `previous` play.
+    pub fn is_scoring(&self, previous: Option<&Play>) -> bool {
+        let (h, v) = previous.map_or((0, 0), |x| (x.h_score, x.v_score));
+        self.h_score != h || self.v_score != v
+    }
+
+    /// Description without its `[LAL 2-0] ` prefix of the team and score.
+    pub fn action(&self) -> &'lf str {
+        match self.description.find("] ") {
+            Some(i) if self.description.starts_with('[') => &self.description[i + 2..],
+            _ => self.description,
+        }
+    }
+
+    /// Players of a substitution, parsed from descriptions like
+    /// `[LAL] Caruso Substitution replaced by Kuzma` or `SUB: Kuzma FOR Caruso`.
+    pub fn substitution(&self) -> Option<Substitution<'lf>> {
+        let description = self.action();
+        let (leaving, entering) = if let Some(i) = description.find(" Substitution replaced by ") {
+            (&description[..i], &description[i + 26..])
+        } else {
+            let rest = description.strip_prefix("SUB: ")?;
+            let i = rest.find(" FOR ")?;
+            (&rest[i + 5..], &rest[..i])
+        };
+        Some(Substitution {
+            leaving: leaving.trim(),
+            entering: entering.trim(),
+        })
+        .filter(|x| !x.leaving.is_empty() && !x.entering.is_empty())
+    }
+}
+
+/// Names of the players of a substitution, as written in the play by play.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Substitution<'lf> {
+    /// Name of the player going to the bench.
+    pub leaving: &'lf str,
+    /// Name of the player coming in.
+    pub entering: &'lf str,
+}
+
+/// What a play is about, parsed from its description.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Action {
+    FieldGoal { made: bool, three: bool },
+    FreeThrow { made: bool },
+    Rebound,
+    Turnover,
+    Foul { personal: bool },
+}
+
+impl Action {
+    /// Parses the part of a description following the player name, like
+    /// `3pt Shot: Missed` or `Free Throw 1 of 2 Made (2 PTS)`.
+    pub(crate) fn parse(action: &str) -> Option<Self> {
+        if action.contains("Free Throw") {
+            Some(Action::FreeThrow {
+                made: action.contains("Made"),
+            })
+        } else if action.contains("Rebound") {
+            Some(Action::Rebound)
+        } else if action.contains("Turnover") {
+            Some(Action::Turnover)
+        } else if action.contains("Foul") {
+            Some(Action::Foul {
+                personal: !action.contains("Technical"),
+            })
+        } else if action.contains("Made") || action.contains("Missed") {
+            Some(Action::FieldGoal {
+                made: action.contains("Made"),
+                three: action.to_ascii_lowercase().contains("3pt"),
+            })
+        } else {
+            None
+        }
+    }
 }
 
 impl<'lf, 'de> Deserialize<'de> for Play<'lf>
@@ -96,14 +217,24 @@ where
             home_score: &'lf str,
             visitor_score: &'lf str,
             period: &'lf str,
+            #[serde(default)]
+            team_abr: &'lf str,
+            #[serde(default)]
+            person_id: &'lf str,
+            #[serde(default)]
+            x: &'lf str,
+            #[serde(default)]
+            y: &'lf str,
         }
 
         let helper = Root::deserialize(deserializer)?;
 
-        let clock = if helper.clock.is_empty() {
-            "12:00"
-        } else {
-            helper.clock
+        let period = helper.period.parse().unwrap_or(1);
+        // Plays at the start of a period may come without a clock
+        let clock = match helper.clock {
+            "" if period > 4 => "5:00",
+            "" => "12:00",
+            clock => clock,
         };
 
         Ok(Self {
@@ -111,26 +242,317 @@ where
             description: helper.description,
             h_score: helper.home_score.parse().unwrap_or(0),
             v_score: helper.visitor_score.parse().unwrap_or(0),
-            period: match helper.period {
-                "1" => Period::Q1,
-                "2" => Period::Q2,
-                "3" => Period::Q3,
-                "4" => Period::Q4,
-                _ => Period::OT,
-            },
+            period,
+            team: Some(helper.team_abr).filter(|x| !x.is_empty()),
+            person_id: Some(helper.person_id).filter(|x| !x.is_empty() && *x != "0"),
+            shot: Shot::new(helper.description, helper.x, helper.y),
         })
     }
 }
 
-#[derive(Debug)]
+/// Length in minutes of `period`, overtimes lasting five minutes.
+pub fn period_length(period: u8) -> f32 {
+    if period > 4 {
+        5.0
+    } else {
+        12.0
+    }
+}
+
+/// Game minutes elapsed at the start of `period`.
+pub fn period_start(period: u8) -> f32 {
+    let period = period.max(1) as f32;
+    if period > 4.0 {
+        48.0 + (period - 5.0) * 5.0
+    } else {
+        (period - 1.0) * 12.0
+    }
+}
+
+/// Name of `period`, e.g. `Q3`, `OT` or `2OT`.
+pub fn period_name(period: u8) -> String {
+    match period {
+        0..=4 => format!("Q{}", period),
+        5 => "OT".to_string(),
+        x => format!("{}OT", x - 4),
+    }
+}
+
+/// Number of periods of chronological `plays`, at least the four quarters.
+pub fn periods(plays: &[Play]) -> u8 {
+    plays.iter().map(|x| x.period).max().unwrap_or(0).max(4)
+}
+
+/// Game minutes elapsed at each play.
+pub fn elapsed(plays: &[Play]) -> Vec<f32> {
+    plays
+        .iter()
+        .map(|play| {
+            period_start(play.period) + period_length(play.period)
+                - stats::parse_minutes(play.clock)
+        })
+        .collect()
+}
+
+/// Unanswered points scored by one team.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Run {
+    /// Whether the home team scored the run.
+    pub home: bool,
+    /// Points of the run.
+    pub points: u8,
+    /// Index of the first scoring play of the run.
+    pub start: usize,
+    /// Index of the last scoring play of the run.
+    pub end: usize,
+}
+
+/// Every run of at least `min_points`, in chronological order. The last run
+/// is still going on. Score corrections lowering a score do not end a run.
+pub fn runs(plays: &[Play], min_points: u8) -> Vec<Run> {
+    let mut runs: Vec<Run> = Vec::new();
+    // The current run and the score of its team before it
+    let mut current: Option<(Run, u8)> = None;
+    for (i, play) in plays.iter().enumerate() {
+        let previous = i.checked_sub(1).and_then(|x| plays.get(x));
+        let (h, v) = previous.map_or((0, 0), |x| (x.h_score, x.v_score));
+        let score = |home: bool| if home { play.h_score } else { play.v_score };
+        let home = if play.h_score > h {
+            true
+        } else if play.v_score > v {
+            false
+        } else {
+            if let Some((run, base)) = current.as_mut() {
+                run.points = score(run.home).saturating_sub(*base);
+            }
+            continue;
+        };
+        match current.as_mut() {
+            Some((run, base)) if run.home == home => {
+                run.points = score(home).saturating_sub(*base);
+                run.end = i;
+            }
+            _ => {
+                runs.extend(current.map(|x| x.0).filter(|x| x.points >= min_points));
+                let base = if home { h } else { v };
+                current = Some((
+                    Run {
+                        home,
+                        points: score(home) - base,
+                        start: i,
+                        end: i,
+                    },
+                    base,
+                ));
+            }
+        }
+    }
+    runs.extend(current.map(|x| x.0).filter(|x| x.points >= min_points));
+    runs
+}
+
+/// Runs of at least this many points are worth pointing out.
+pub const NOTABLE_RUN: u8 = 8;
+
+/// Lead changes, ties, largest leads and time spent leading, computed from
+/// the play by play.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GameFlow {
+    /// Times the lead changed hands.
+    pub lead_changes: u16,
+    /// Times the score was tied after a team led.
+    pub times_tied: u16,
+    /// Largest lead of the home team.
+    pub h_largest_lead: u8,
+    /// Largest lead of the visiting team.
+    pub v_largest_lead: u8,
+    /// Minutes the home team spent leading.
+    pub h_time_leading: f32,
+    /// Minutes the visiting team spent leading.
+    pub v_time_leading: f32,
+    /// Runs of `NOTABLE_RUN` points or more.
+    pub runs: Vec<Run>,
+}
+
+impl GameFlow {
+    /// Computes the game flow of chronological `plays`.
+    pub fn new(plays: &[Play]) -> Self {
+        let mut flow = GameFlow {
+            runs: runs(plays, NOTABLE_RUN),
+            ..GameFlow::default()
+        };
+        let elapsed = elapsed(plays);
+        // Some(true) when the home team leads, None when tied
+        let mut leader = None;
+        let mut last_leader = None;
+        for (i, play) in plays.iter().enumerate() {
+            if let Some(home) = leader {
+                let minutes = elapsed[i] - i.checked_sub(1).map_or(0.0, |x| elapsed[x]);
+                if home {
+                    flow.h_time_leading += minutes.max(0.0);
+                } else {
+                    flow.v_time_leading += minutes.max(0.0);
+                }
+            }
+            let (h, v) = (play.h_score, play.v_score);
+            let new_leader = match h.cmp(&v) {
+                Ordering::Greater => Some(true),
+                Ordering::Less => Some(false),
+                Ordering::Equal => None,
+            };
+            if new_leader.is_none() && leader.is_some() {
+                flow.times_tied += 1;
+            }
+            if new_leader.is_some() {
+                if last_leader.is_some() && last_leader != new_leader {
+                    flow.lead_changes += 1;
+                }
+                last_leader = new_leader;
+            }
+            leader = new_leader;
+            flow.h_largest_lead = flow.h_largest_lead.max(h.saturating_sub(v));
+            flow.v_largest_lead = flow.v_largest_lead.max(v.saturating_sub(h));
+        }
+        flow
+    }
+}
+
+/// Distance from the basket to the corner three line, in tenths of feet.
+pub const CORNER_THREE_X: f32 = 220.0;
+
+/// Distance from the basket towards half court where the corner three line
+/// meets the arc, in tenths of feet.
+pub const CORNER_THREE_Y: f32 = 89.5;
+
+/// A field goal attempt. Coordinates are in tenths of feet from the
+/// basket, `x` across the court and `y` towards half court.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Shot {
+    /// Distance across the court, negative to the left of the basket.
+    pub x: f32,
+    /// Distance from the basket towards half court.
+    pub y: f32,
+    /// Whether the shot went in.
+    pub made: bool,
+}
+
+impl Shot {
+    /// Parses the location of a play described as a made or missed shot,
+    /// free throws excluded.
+    fn new(description: &str, x: &str, y: &str) -> Option<Shot> {
+        let made = if description.contains("Missed") {
+            false
+        } else if description.contains("Made") {
+            true
+        } else {
+            return None;
+        };
+        if description.contains("Free Throw") {
+            return None;
+        }
+        Some(Shot {
+            x: x.trim().parse().ok()?,
+            y: y.trim().parse().ok()?,
+            made,
+        })
+    }
+
+    /// Distance to the basket in feet.
+    pub fn distance(&self) -> f32 {
+        self.x.hypot(self.y) / 10.0
+    }
+
+    /// Area of the half court the shot was taken from.
+    pub fn zone(&self) -> Zone {
+        let distance = self.distance();
+        if self.x.abs() >= CORNER_THREE_X && self.y <= CORNER_THREE_Y {
+            Zone::Corner3
+        } else if distance >= 23.75 {
+            Zone::AboveBreak3
+        } else if distance <= 4.0 {
+            Zone::RestrictedArea
+        } else if self.x.abs() < 80.0 && self.y < 137.5 {
+            Zone::Paint
+        } else {
+            Zone::MidRange
+        }
+    }
+}
+
+/// Area of the half court a shot was taken from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Zone {
+    /// Within four feet of the basket.
+    RestrictedArea,
+    /// Rest of the lane.
+    Paint,
+    /// Two pointers outside of the paint.
+    MidRange,
+    /// Three pointers from along the baselines.
+    Corner3,
+    /// Three pointers from the arc.
+    AboveBreak3,
+}
+
+impl Zone {
+    /// Every zone, closest to the basket first.
+    pub const ALL: [Zone; 5] = [
+        Zone::RestrictedArea,
+        Zone::Paint,
+        Zone::MidRange,
+        Zone::Corner3,
+        Zone::AboveBreak3,
+    ];
+
+    /// Whether shots from the zone are worth three points.
+    pub fn is_three(self) -> bool {
+        matches!(self, Zone::Corner3 | Zone::AboveBreak3)
+    }
+}
+
+impl fmt::Display for Zone {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Zone::RestrictedArea => f.write_str("Restricted area"),
+            Zone::Paint => f.write_str("Paint"),
+            Zone::MidRange => f.write_str("Mid-range"),
+            Zone::Corner3 => f.write_str("Corner 3"),
+            Zone::AboveBreak3 => f.write_str("Above the break 3"),
+        }
+    }
+}
+
+/// Period of a play with every overtime grouped as `OT`, for filtering.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Period {
+    /// First quarter.
     Q1,
+    /// Second quarter.
     Q2,
+    /// Third quarter.
     Q3,
+    /// Fourth quarter.
     Q4,
+    /// Any overtime.
     OT,
 }
 
+impl Period {
+    /// Every period, in order.
+    pub const ALL: [Period; 5] = [Period::Q1, Period::Q2, Period::Q3, Period::Q4, Period::OT];
+
+    /// Period of the play period number `period`.
+    pub fn new(period: u8) -> Self {
+        match period {
+            0 | 1 => Period::Q1,
+            2 => Period::Q2,
+            3 => Period::Q3,
+            4 => Period::Q4,
+            _ => Period::OT,
+        }
+    }
+}
+
 impl fmt::Display for Period {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
@@ -142,3 +564,248 @@ impl fmt::Display for Period {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn play(period: u8, clock: &'static str, h_score: u8, v_score: u8) -> Play<'static> {
+        Play {
+            clock,
+            description: "",
+            h_score,
+            v_score,
+            period,
+            team: None,
+            person_id: None,
+            shot: None,
+        }
+    }
+
+    fn described(description: &'static str) -> Play<'static> {
+        Play {
+            description,
+            team: Some("LAL"),
+            ..play(1, "12:00", 0, 0)
+        }
+    }
+
+    #[test]
+    fn substitution() {
+        let sub = described("[LAL] Caruso Substitution replaced by Kuzma")
+            .substitution()
+            .unwrap();
+        assert_eq!((sub.leaving, sub.entering), ("Caruso", "Kuzma"));
+        let sub = described("SUB: Kuzma FOR Caruso").substitution().unwrap();
+        assert_eq!((sub.leaving, sub.entering), ("Caruso", "Kuzma"));
+        let sub = described("[LAL] Horton-Tucker Substitution replaced by Davis")
+            .substitution()
+            .unwrap();
+        assert_eq!((sub.leaving, sub.entering), ("Horton-Tucker", "Davis"));
+        assert!(described("[LAL] Caruso Substitution replaced by ")
+            .substitution()
+            .is_none());
+        assert!(described("[LAL] Caruso Jump Shot: Missed")
+            .substitution()
+            .is_none());
+    }
+
+    #[test]
+    fn game_flow() {
+        let plays = [
+            play(1, "12:00", 0, 0),
+            play(1, "11:00", 2, 0),
+            play(1, "10:00", 2, 3),
+            play(1, "9:00", 5, 3),
+            play(1, "8:00", 5, 5),
+            play(1, "7:00", 5, 7),
+            play(1, "6:00", 15, 7),
+        ];
+        let flow = GameFlow::new(&plays);
+        assert_eq!(flow.lead_changes, 4);
+        assert_eq!(flow.times_tied, 1);
+        assert_eq!(flow.h_largest_lead, 8);
+        assert_eq!(flow.v_largest_lead, 2);
+        assert_eq!(flow.h_time_leading, 2.0);
+        assert_eq!(flow.v_time_leading, 2.0);
+    }
+
+    #[test]
+    fn overtime_elapsed() {
+        let json = r#"[
+            {"clock":"","description":"Start Period","home_score":"100",
+             "visitor_score":"100","period":"5"},
+            {"clock":"","description":"Start Period","home_score":"110",
+             "visitor_score":"110","period":"6"}
+        ]"#;
+        let parsed: Vec<Play> = serde_json::from_str(json).unwrap();
+        assert_eq!((parsed[0].period, parsed[0].clock), (5, "5:00"));
+        assert_eq!((parsed[1].period, parsed[1].clock), (6, "5:00"));
+
+        let plays = [
+            play(4, "0:00", 100, 100),
+            parsed.into_iter().next().unwrap(),
+            play(5, "0:00", 110, 110),
+            play(6, "4:00", 112, 110),
+            play(6, "0:00", 112, 110),
+        ];
+        assert_eq!(elapsed(&plays), vec![48.0, 48.0, 53.0, 54.0, 58.0]);
+        let flow = GameFlow::new(&plays);
+        assert_eq!(flow.h_time_leading, 4.0);
+        assert_eq!(flow.v_time_leading, 0.0);
+        assert_eq!(period_name(6), "2OT");
+        assert_eq!(Period::new(6), Period::OT);
+    }
+
+    #[test]
+    fn overtime_periods() {
+        let plays = [
+            play(1, "12:00", 0, 0),
+            play(4, "0:00", 100, 100),
+            play(5, "0:00", 110, 110),
+            play(6, "0:00", 112, 110),
+        ];
+        assert_eq!(periods(&plays[..2]), 4);
+        assert_eq!(periods(&plays), 6);
+        // Each overtime starts its own five minute column
+        let starts: Vec<f32> = (1..=periods(&plays)).map(period_start).collect();
+        assert_eq!(starts, vec![0.0, 12.0, 24.0, 36.0, 48.0, 53.0]);
+        assert_eq!(period_start(periods(&plays) + 1), 58.0);
+    }
+
+    #[test]
+    fn run_across_periods() {
+        let plays = [
+            play(1, "11:00", 2, 0),
+            play(1, "1:00", 2, 3),
+            play(1, "0:30", 2, 6),
+            play(1, "0:00", 2, 6),
+            play(2, "11:30", 2, 9),
+            play(2, "11:00", 4, 9),
+        ];
+        let run = Run {
+            home: false,
+            points: 9,
+            start: 1,
+            end: 4,
+        };
+        assert_eq!(runs(&plays, NOTABLE_RUN), vec![run]);
+        assert_eq!(GameFlow::new(&plays).runs, vec![run]);
+        // The home run still going on
+        assert_eq!(
+            runs(&plays, 1).last().map(|x| (x.home, x.points)),
+            Some((true, 2))
+        );
+    }
+
+    #[test]
+    fn run_with_score_correction() {
+        let plays = [
+            play(1, "12:00", 0, 0),
+            play(1, "11:00", 0, 3),
+            play(1, "10:00", 0, 6),
+            play(1, "9:50", 0, 5),
+            play(1, "9:00", 0, 8),
+            play(1, "8:00", 2, 8),
+        ];
+        assert_eq!(
+            runs(&plays, NOTABLE_RUN),
+            vec![Run {
+                home: false,
+                points: 8,
+                start: 1,
+                end: 4,
+            }]
+        );
+        let flow = GameFlow::new(&plays);
+        assert_eq!(flow.v_largest_lead, 8);
+        assert_eq!(flow.lead_changes, 0);
+    }
+
+    #[test]
+    fn shot_zones() {
+        let zone = |x, y| Shot { x, y, made: true }.zone();
+        assert_eq!(zone(0.0, 30.0), Zone::RestrictedArea);
+        assert_eq!(zone(50.0, 100.0), Zone::Paint);
+        assert_eq!(zone(150.0, 100.0), Zone::MidRange);
+        assert_eq!(zone(0.0, 200.0), Zone::MidRange);
+        assert_eq!(zone(225.0, 50.0), Zone::Corner3);
+        assert_eq!(zone(-225.0, 89.0), Zone::Corner3);
+        // Past the end of the corner line drawn on the court
+        assert_eq!(zone(-225.0, 91.0), Zone::AboveBreak3);
+        assert_eq!(zone(0.0, 250.0), Zone::AboveBreak3);
+        // Just inside the corner three line, and on the arc
+        assert_eq!(zone(219.0, 50.0), Zone::MidRange);
+        assert_eq!(zone(0.0, 237.5), Zone::AboveBreak3);
+    }
+
+    #[test]
+    fn shot_parsing() {
+        let made = Shot::new("[LAL 2-0] James Jump Shot: Made (2 PTS)", "10", "200");
+        assert_eq!(
+            made,
+            Some(Shot {
+                x: 10.0,
+                y: 200.0,
+                made: true
+            })
+        );
+        let missed = Shot::new("[LAL] James 3pt Shot: Missed", "-230", "20");
+        assert_eq!(
+            missed.map(|x| (x.made, x.zone())),
+            Some((false, Zone::Corner3))
+        );
+        assert_eq!(
+            Shot::new("[LAL 3-0] James Free Throw 1 of 2 Made (3 PTS)", "0", "0"),
+            None
+        );
+        assert_eq!(
+            Shot::new("[LAL] James Free Throw 2 of 2 Missed", "0", "0"),
+            None
+        );
+        assert_eq!(
+            Shot::new("[LAL] James Rebound (Off:0 Def:1)", "5", "5"),
+            None
+        );
+        assert_eq!(
+            Shot::new("[LAL] James Layup Shot: Made (4 PTS)", "", ""),
+            None
+        );
+    }
+
+    #[test]
+    fn parse_actions() {
+        assert_eq!(
+            Action::parse("Free Throw 1 of 2 Made (3 PTS)"),
+            Some(Action::FreeThrow { made: true })
+        );
+        assert_eq!(
+            Action::parse("Free Throw Technical Missed"),
+            Some(Action::FreeThrow { made: false })
+        );
+        assert_eq!(
+            Action::parse("3pt Shot: Missed Block: Embiid (1 BLK)"),
+            Some(Action::FieldGoal {
+                made: false,
+                three: true
+            })
+        );
+        assert_eq!(
+            Action::parse("Layup Shot: Made (2 PTS) Assist: Harris (1 AST)"),
+            Some(Action::FieldGoal {
+                made: true,
+                three: false
+            })
+        );
+        assert_eq!(Action::parse("Team Rebound"), Some(Action::Rebound));
+        assert_eq!(
+            Action::parse("Turnover : Bad Pass (1 TO) Steal:Harris (1 ST)"),
+            Some(Action::Turnover)
+        );
+        assert_eq!(
+            Action::parse("Technical Foul (1 FTA)"),
+            Some(Action::Foul { personal: false })
+        );
+        assert_eq!(Action::parse("Substitution replaced by Curry"), None);
+    }
+}
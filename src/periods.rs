@@ -0,0 +1,463 @@
+use crate::boxscore::BoxScore;
+use crate::lineups::{self, Lineups};
+use crate::pbp::{self, Action, Period, Play};
+use crate::stats::Stats;
+use crate::utils::Player;
+use std::fmt;
+
+/// Part of a game a boxscore can be restricted to, every overtime counting
+/// as one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Segment {
+    /// First quarter.
+    Q1,
+    /// Second quarter.
+    Q2,
+    /// First and second quarters.
+    FirstHalf,
+    /// Third quarter.
+    Q3,
+    /// Fourth quarter.
+    Q4,
+    /// Third and fourth quarters.
+    SecondHalf,
+    /// Every overtime.
+    OT,
+}
+
+impl Segment {
+    /// Every segment, in the order they are cycled through.
+    pub const ALL: [Segment; 7] = [
+        Segment::Q1,
+        Segment::Q2,
+        Segment::FirstHalf,
+        Segment::Q3,
+        Segment::Q4,
+        Segment::SecondHalf,
+        Segment::OT,
+    ];
+
+    /// Whether the plays of `period` are part of the segment.
+    pub fn contains(self, period: Period) -> bool {
+        match self {
+            Segment::Q1 => period == Period::Q1,
+            Segment::Q2 => period == Period::Q2,
+            Segment::FirstHalf => matches!(period, Period::Q1 | Period::Q2),
+            Segment::Q3 => period == Period::Q3,
+            Segment::Q4 => period == Period::Q4,
+            Segment::SecondHalf => matches!(period, Period::Q3 | Period::Q4),
+            Segment::OT => period == Period::OT,
+        }
+    }
+}
+
+impl fmt::Display for Segment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Segment::Q1 => f.write_str("Q1"),
+            Segment::Q2 => f.write_str("Q2"),
+            Segment::FirstHalf => f.write_str("1H"),
+            Segment::Q3 => f.write_str("Q3"),
+            Segment::Q4 => f.write_str("Q4"),
+            Segment::SecondHalf => f.write_str("2H"),
+            Segment::OT => f.write_str("OT"),
+        }
+    }
+}
+
+/// Name following `label` in a description, e.g. `Smart` for `Assist: ` in
+/// `Assist: Smart (3 AST)`.
+fn credited<'a>(description: &'a str, label: &str) -> Option<&'a str> {
+    let start = description.find(label)? + label.len();
+    let name = &description[start..];
+    let name = name.find(" (").map_or(name, |x| &name[..x]);
+    Some(name.trim()).filter(|x| !x.is_empty())
+}
+
+/// Stats of the players and teams of a game over a segment, counted from
+/// the play by play.
+#[derive(Debug, Clone, Default)]
+pub struct SegmentStats<'lf> {
+    /// Person ids and stats, in the boxscore order.
+    pub players: Vec<(&'lf str, Stats)>,
+    /// Totals of the home team.
+    pub h_team: Stats,
+    /// Totals of the visiting team.
+    pub v_team: Stats,
+}
+
+impl<'lf> SegmentStats<'lf> {
+    /// Stats over `segment`, or the whole game without one. Points of the
+    /// teams come from the score, minutes and plus/minus from `lineups`.
+    pub fn new(
+        boxscore: &BoxScore<'lf>,
+        plays: &[Play<'lf>],
+        lineups: &Lineups<'lf>,
+        segment: Option<Segment>,
+    ) -> Self {
+        let mut stats = SegmentStats {
+            players: boxscore
+                .players
+                .iter()
+                .map(|x| (x.person_id(), Stats::default()))
+                .collect(),
+            ..SegmentStats::default()
+        };
+        let team_players = |team_id: &str| -> Vec<&Player<'lf>> {
+            boxscore
+                .players
+                .iter()
+                .filter(|x| x.team_id == Some(team_id))
+                .collect()
+        };
+        let h_players = team_players(boxscore.h_team.team_id);
+        let v_players = team_players(boxscore.v_team.team_id);
+        let home: Vec<&str> = h_players.iter().map(|x| x.person_id()).collect();
+        let elapsed = pbp::elapsed(plays);
+        // Team of the last missed shot, telling offensive rebounds apart
+        let mut last_miss: Option<&str> = None;
+        for (i, play) in plays.iter().enumerate() {
+            let counted = segment.is_none_or(|x| x.contains(Period::new(play.period)));
+            let previous = i.checked_sub(1).map(|x| &plays[x]);
+            let (h, v) = previous.map_or((0, 0), |x| (x.h_score, x.v_score));
+            let (h, v) = (
+                play.h_score.saturating_sub(h) as u16,
+                play.v_score.saturating_sub(v) as u16,
+            );
+            if counted {
+                stats.h_team.points += h;
+                stats.v_team.points += v;
+                if i > 0 {
+                    let minutes = (elapsed[i] - elapsed[i - 1]).max(0.0);
+                    for &person_id in lineups.on_court.get(i).into_iter().flatten() {
+                        let (scored, allowed) = if home.contains(&person_id) {
+                            (h, v)
+                        } else {
+                            (v, h)
+                        };
+                        if let Some(s) = stats.player_mut(person_id) {
+                            s.minutes += minutes;
+                            s.plus_minus += scored as i16 - allowed as i16;
+                        }
+                    }
+                }
+            }
+
+            let tri_code = match play.team {
+                Some(x) => x,
+                None => continue,
+            };
+            let (teammates, opponents) = if tri_code == boxscore.h_team.tri_code {
+                (&h_players, &v_players)
+            } else {
+                (&v_players, &h_players)
+            };
+            let description = play.action();
+            // The longest last name the description starts with
+            let actor = teammates
+                .iter()
+                .filter(|x| description.starts_with(&format!("{} ", x.last_name)))
+                .max_by_key(|x| x.last_name.len());
+            let rest = actor.map_or(description, |x| &description[x.last_name.len()..]);
+            let actor = play
+                .person_id
+                .filter(|x| teammates.iter().any(|y| y.person_id() == *x))
+                .or_else(|| actor.map(|x| x.person_id()));
+            let action = match Action::parse(rest) {
+                Some(x) => x,
+                None => continue,
+            };
+            let offensive = action == Action::Rebound && last_miss == Some(tri_code);
+            match action {
+                Action::FieldGoal { made: false, .. } | Action::FreeThrow { made: false } => {
+                    last_miss = Some(tri_code)
+                }
+                Action::Rebound => last_miss = None,
+                _ => (),
+            }
+            if !counted {
+                continue;
+            }
+            if let Some(s) = actor.and_then(|x| stats.player_mut(x)) {
+                match action {
+                    Action::FieldGoal { made, three } => {
+                        s.fga += 1;
+                        s.fgm += made as u16;
+                        s.tpa += three as u16;
+                        s.tpm += (made && three) as u16;
+                    }
+                    Action::FreeThrow { made } => {
+                        s.fta += 1;
+                        s.ftm += made as u16;
+                    }
+                    Action::Rebound if offensive => s.off_reb += 1,
+                    Action::Rebound => s.def_reb += 1,
+                    Action::Turnover => s.turnovers += 1,
+                    Action::Foul { personal } => s.p_fouls += personal as u16,
+                }
+                s.points += if tri_code == boxscore.h_team.tri_code {
+                    h
+                } else {
+                    v
+                };
+            }
+            let assist =
+                credited(rest, "Assist: ").and_then(|x| lineups::find_player(teammates, x));
+            if let Some(s) = assist.and_then(|x| stats.player_mut(x)) {
+                s.assists += 1;
+            }
+            let steal = credited(rest, "Steal:").and_then(|x| lineups::find_player(opponents, x));
+            if let Some(s) = steal.and_then(|x| stats.player_mut(x)) {
+                s.steals += 1;
+            }
+            let block = credited(rest, "Block: ").and_then(|x| lineups::find_player(opponents, x));
+            if let Some(s) = block.and_then(|x| stats.player_mut(x)) {
+                s.blocks += 1;
+                if let Some(s) = actor.and_then(|x| stats.player_mut(x)) {
+                    s.blocks_against += 1;
+                }
+            }
+        }
+        stats.add_totals(boxscore);
+        stats
+    }
+
+    fn player_mut(&mut self, person_id: &str) -> Option<&mut Stats> {
+        self.players
+            .iter_mut()
+            .find(|x| x.0 == person_id)
+            .map(|x| &mut x.1)
+    }
+
+    /// Sums the players into their team, keeping the points of the score,
+    /// and fills the percentages.
+    fn add_totals(&mut self, boxscore: &BoxScore) {
+        for (player, (_, s)) in boxscore.players.iter().zip(self.players.iter_mut()) {
+            s.tot_reb = s.off_reb + s.def_reb;
+            set_percentages(s);
+            let team = if player.team_id == Some(boxscore.h_team.team_id) {
+                &mut self.h_team
+            } else if player.team_id == Some(boxscore.v_team.team_id) {
+                &mut self.v_team
+            } else {
+                continue;
+            };
+            let points = team.points;
+            add(team, s);
+            team.points = points;
+        }
+        let (h, v) = (self.h_team.points as i16, self.v_team.points as i16);
+        self.h_team.plus_minus = h - v;
+        self.v_team.plus_minus = v - h;
+        set_percentages(&mut self.h_team);
+        set_percentages(&mut self.v_team);
+    }
+
+    /// Stats of a player, `0` for the ones not in the boxscore.
+    pub fn player(&self, person_id: &str) -> Stats {
+        self.players
+            .iter()
+            .find(|x| x.0 == person_id)
+            .map(|x| x.1)
+            .unwrap_or_default()
+    }
+
+    /// Totals of the home team, or of the visiting team.
+    pub fn team(&self, home: bool) -> Stats {
+        if home {
+            self.h_team
+        } else {
+            self.v_team
+        }
+    }
+
+    /// Players whose points, shots, rebounds or assists differ from the
+    /// boxscore feed, only meaningful for the whole game.
+    pub fn mismatches(&self, boxscore: &BoxScore) -> usize {
+        let counts = |s: Stats| [s.points, s.fgm, s.fga, s.ftm, s.fta, s.tot_reb, s.assists];
+        boxscore
+            .players
+            .iter()
+            .filter(|x| counts(Stats::from(*x)) != counts(self.player(x.person_id())))
+            .count()
+    }
+}
+
+fn add(team: &mut Stats, s: &Stats) {
+    team.minutes += s.minutes;
+    team.points += s.points;
+    team.fgm += s.fgm;
+    team.fga += s.fga;
+    team.ftm += s.ftm;
+    team.fta += s.fta;
+    team.tpm += s.tpm;
+    team.tpa += s.tpa;
+    team.off_reb += s.off_reb;
+    team.def_reb += s.def_reb;
+    team.tot_reb += s.tot_reb;
+    team.assists += s.assists;
+    team.p_fouls += s.p_fouls;
+    team.steals += s.steals;
+    team.turnovers += s.turnovers;
+    team.blocks += s.blocks;
+    team.blocks_against += s.blocks_against;
+}
+
+fn set_percentages(s: &mut Stats) {
+    let pct = |made: u16, attempts: u16| {
+        if attempts == 0 {
+            0.0
+        } else {
+            100.0 * made as f32 / attempts as f32
+        }
+    };
+    s.fgp = pct(s.fgm, s.fga);
+    s.ftp = pct(s.ftm, s.fta);
+    s.tpp = pct(s.tpm, s.tpa);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn play(
+        team: &'static str,
+        h_score: u8,
+        v_score: u8,
+        description: &'static str,
+    ) -> Play<'static> {
+        Play {
+            clock: "12:00",
+            description,
+            h_score,
+            v_score,
+            period: 1,
+            team: Some(team),
+            person_id: None,
+            shot: None,
+        }
+    }
+
+    /// A starter with the stats of its boxscore line, e.g. `"fga":"1"`.
+    fn player(person_id: &str, last_name: &str, team_id: &str, line: &str) -> Player<'static> {
+        let json = format!(
+            r#"{{"personId":"{}","firstName":"X","lastName":"{}","teamId":"{}","pos":"F",{}}}"#,
+            person_id, last_name, team_id, line
+        );
+        serde_json::from_str(Box::leak(json.into_boxed_str())).unwrap()
+    }
+
+    #[test]
+    fn credited_players() {
+        assert_eq!(
+            credited("3pt Shot: Missed Block: Embiid (1 BLK)", "Block: "),
+            Some("Embiid")
+        );
+        assert_eq!(credited("Jump Shot: Missed", "Block: "), None);
+        assert_eq!(
+            credited("Turnover : Bad Pass (1 TO) Steal:Harris (1 ST)", "Steal:"),
+            Some("Harris")
+        );
+        assert_eq!(
+            credited("Layup Shot: Made (2 PTS) Assist: Smart (3 AST)", "Assist: "),
+            Some("Smart")
+        );
+    }
+
+    #[test]
+    fn full_game_matches_feed() {
+        let json = r#"{"basicGameData":{"clock":"","period":{"current":1,"type":0,"maxRegular":4},
+            "vTeam":{"teamId":"2","triCode":"BOS","win":"0","loss":"0","score":"2","linescore":[]},
+            "hTeam":{"teamId":"1","triCode":"PHI","win":"0","loss":"0","score":"3","linescore":[]}}}"#;
+        let mut boxscore: BoxScore = serde_json::from_str(json).unwrap();
+        boxscore.players = vec![
+            player(
+                "1",
+                "Embiid",
+                "1",
+                r#""points":"3","fgm":"1","fga":"1","ftm":"1","fta":"2","offReb":"1",
+                "totReb":"1","blocks":"1""#,
+            ),
+            player(
+                "3",
+                "Harris",
+                "1",
+                r#""fga":"1","assists":"1","steals":"1""#,
+            ),
+            player(
+                "2",
+                "Tatum",
+                "2",
+                r#""points":"2","fgm":"1","fga":"2","tpa":"1","pFouls":"1""#,
+            ),
+            player(
+                "4",
+                "Smart",
+                "2",
+                r#""defReb":"1","totReb":"1","turnovers":"1""#,
+            ),
+        ];
+        let plays = [
+            play("PHI", 0, 0, "[PHI] Harris Jump Shot: Missed"),
+            // Offensive rebound after the team's own miss
+            play("PHI", 0, 0, "[PHI] Embiid Rebound (Off:1 Def:0)"),
+            play(
+                "PHI",
+                2,
+                0,
+                "[PHI 2-0] Embiid Layup Shot: Made (2 PTS) Assist: Harris (1 AST)",
+            ),
+            play(
+                "BOS",
+                2,
+                0,
+                "[BOS] Tatum 3pt Shot: Missed Block: Embiid (1 BLK)",
+            ),
+            // Credited to nobody
+            play("BOS", 2, 0, "[BOS] Team Rebound"),
+            play(
+                "BOS",
+                2,
+                0,
+                "[BOS] Smart Turnover : Bad Pass (1 TO) Steal:Harris (1 ST)",
+            ),
+            play("BOS", 2, 0, "[BOS] Tatum Foul: Personal (1 PF)"),
+            play(
+                "PHI",
+                3,
+                0,
+                "[PHI 3-0] Embiid Free Throw 1 of 2 Made (3 PTS)",
+            ),
+            play("PHI", 3, 0, "[PHI] Embiid Free Throw 2 of 2 Missed"),
+            // Defensive rebound after the other team's miss
+            play("BOS", 3, 0, "[BOS] Smart Rebound (Off:0 Def:1)"),
+            play("BOS", 3, 2, "[BOS 3-2] Tatum Jump Shot: Made (2 PTS)"),
+        ];
+        let lineups = Lineups::new(&boxscore, &plays);
+        let stats = SegmentStats::new(&boxscore, &plays, &lineups, None);
+
+        assert_eq!(stats.mismatches(&boxscore), 0);
+        let embiid = stats.player("1");
+        assert_eq!((embiid.off_reb, embiid.def_reb, embiid.blocks), (1, 0, 1));
+        assert_eq!(stats.player("2").blocks_against, 1);
+        assert_eq!(stats.player("4").def_reb, 1);
+        let (h, v) = (stats.team(true), stats.team(false));
+        assert_eq!((h.points, h.fgm, h.fga, h.ftm, h.fta), (3, 1, 2, 1, 2));
+        assert_eq!((h.tot_reb, h.assists, h.steals), (1, 1, 1));
+        assert_eq!((v.points, v.fgm, v.fga, v.tpa), (2, 1, 2, 1));
+        assert_eq!((v.tot_reb, v.turnovers, v.p_fouls), (1, 1, 1));
+        assert_eq!(h.plus_minus, 1);
+
+        // A stat the plays do not back up is reported
+        boxscore.players[1] = player(
+            "3",
+            "Harris",
+            "1",
+            r#""fga":"1","assists":"2","steals":"1""#,
+        );
+        assert_eq!(stats.mismatches(&boxscore), 1);
+        // Nothing of the game happened in the second quarter
+        let q2 = SegmentStats::new(&boxscore, &plays, &lineups, Some(Segment::Q2));
+        assert_eq!(q2.team(true), Stats::default());
+    }
+}
@@ -0,0 +1,119 @@
+use crate::Error;
+use serde::de::Deserialize;
+use serde_derive::Deserialize;
+
+fn url(person_id: &str) -> String {
+    format!(
+        "http://data.nba.com/data/10s/prod/v1/2020/players/{}_profile.json",
+        person_id
+    )
+}
+
+/// Per game averages of a player's latest season, from the player profile
+/// feed. Percentages are between 0 and 100.
+#[derive(Debug, Clone, Default)]
+pub struct SeasonAverages {
+    /// First year of the season, e.g. `2020` for 2020-21.
+    pub season_year: u16,
+    /// Games played.
+    pub games_played: u16,
+    /// Minutes per game.
+    pub mpg: f32,
+    /// Points per game.
+    pub ppg: f32,
+    /// Rebounds per game.
+    pub rpg: f32,
+    /// Assists per game.
+    pub apg: f32,
+    /// Steals per game.
+    pub spg: f32,
+    /// Blocks per game.
+    pub bpg: f32,
+    /// Turnovers per game.
+    pub topg: f32,
+    /// Field goal percentage.
+    pub fgp: f32,
+    /// Three point percentage.
+    pub tpp: f32,
+    /// Free throw percentage.
+    pub ftp: f32,
+}
+
+impl SeasonAverages {
+    /// Fetches the season averages of the player `person_id`, blocking.
+    pub fn new(client: &reqwest::blocking::Client, person_id: &str) -> Result<Self, Error> {
+        let json = client.get(url(person_id)).send()?.text()?;
+
+        Ok(serde_json::from_str(&json)?)
+    }
+
+    /// Fetches the season averages of the player `person_id`.
+    pub async fn fetch(client: &reqwest::Client, person_id: &str) -> Result<Self, Error> {
+        let json = client.get(url(person_id)).send().await?.text().await?;
+
+        Ok(serde_json::from_str(&json)?)
+    }
+}
+
+impl<'de> Deserialize<'de> for SeasonAverages {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct Root {
+            league: League,
+        }
+        #[derive(Deserialize)]
+        struct League {
+            standard: Standard,
+        }
+        #[derive(Deserialize)]
+        struct Standard {
+            stats: Stats,
+        }
+        #[derive(Deserialize)]
+        struct Stats {
+            latest: Latest,
+        }
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Latest {
+            season_year: u16,
+            games_played: String,
+            mpg: String,
+            ppg: String,
+            rpg: String,
+            apg: String,
+            spg: String,
+            bpg: String,
+            topg: String,
+            fgp: String,
+            tpp: String,
+            ftp: String,
+        }
+
+        let x = Root::deserialize(deserializer)?
+            .league
+            .standard
+            .stats
+            .latest;
+        // Missing stats are reported as "-1"
+        let parse = |x: &str| x.parse::<f32>().unwrap_or(0.0).max(0.0);
+
+        Ok(Self {
+            season_year: x.season_year,
+            games_played: x.games_played.parse().unwrap_or(0),
+            mpg: parse(&x.mpg),
+            ppg: parse(&x.ppg),
+            rpg: parse(&x.rpg),
+            apg: parse(&x.apg),
+            spg: parse(&x.spg),
+            bpg: parse(&x.bpg),
+            topg: parse(&x.topg),
+            fgp: parse(&x.fgp),
+            tpp: parse(&x.tpp),
+            ftp: parse(&x.ftp),
+        })
+    }
+}
@@ -1,34 +1,51 @@
 use crate::utils::*;
+use crate::Error;
+use chrono::{DateTime, Utc};
 use reqwest::blocking::Client;
 use serde::de::Deserialize;
 use serde_derive::Deserialize;
 
+/// The season schedule, every game of the preseason, regular season and
+/// playoffs.
 // TODO: remove allocations using custom de impl
 #[derive(Debug)]
 pub struct Schedule<'lf> {
     games: Vec<Game<'lf>>,
 }
 
+const URL: &str = "http://data.nba.com/prod/v1/2020/schedule.json";
+
 impl<'lf> Schedule<'lf> {
-    pub fn new(client: &Client) -> Result<Self, reqwest::Error> {
-        let schedules = Box::leak::<'lf>(Box::new(
-            client
-                .get("http://data.nba.com/prod/v1/2020/schedule.json")
-                .send()?
-                .text()?,
-        ));
-        // let schedules = Box::leak::<'lf>(Box::new(
-        //     std::fs::read_to_string(
-        //         std::path::PathBuf::from(std::env!("CARGO_MANIFEST_DIR").to_string())
-        //             .join("src/schedule.json"),
-        //     )
-        //     .unwrap(),
-        // ));
-
-        // SAFETY: should not fail if json was properly fetched
-        Ok(serde_json::from_str::<Schedule>(&**schedules).unwrap())
+    /// Fetches the schedule, blocking version of `fetch`.
+    pub fn new(client: &Client) -> Result<Self, Error> {
+        let schedules = Box::leak::<'lf>(Box::new(client.get(URL).send()?.text()?));
+
+        Ok(serde_json::from_str::<Schedule>(schedules)?)
+    }
+
+    /// Fetches the schedule.
+    pub async fn fetch(client: &reqwest::Client) -> Result<Self, Error> {
+        let schedules = Box::leak::<'lf>(Box::new(Self::fetch_json(client).await?));
+
+        Self::from_json(schedules)
+    }
+
+    /// Fetches the json of the schedule, to be parsed by `from_json`.
+    pub async fn fetch_json(client: &reqwest::Client) -> Result<String, Error> {
+        Ok(client.get(URL).send().await?.text().await?)
+    }
+
+    /// Parses the json of the schedule feed.
+    pub fn from_json(json: &'lf str) -> Result<Self, Error> {
+        Ok(serde_json::from_str::<Schedule>(json)?)
+    }
+
+    /// Every game, in chronological order.
+    pub fn games(&self) -> &[Game<'lf>] {
+        &self.games
     }
 
+    /// Ids of the games starting on `date`, in `yyyymmdd` Eastern time.
     pub fn get_date_game_id(&self, date: &str) -> Vec<&str> {
         let vec = self.games.as_slice();
         vec.iter()
@@ -36,6 +53,34 @@ impl<'lf> Schedule<'lf> {
             .map(|x| x.game_id)
             .collect()
     }
+
+    /// Games starting on `date`, in `yyyymmdd` Eastern time.
+    pub fn get_date_games(&self, date: &str) -> Vec<&Game<'lf>> {
+        self.games
+            .iter()
+            .filter(|x| x.start_date_eastern == date)
+            .collect()
+    }
+
+    /// Series wins of the home and visitor teams of a playoff `game`,
+    /// counting every finished game of the series up to `game`.
+    pub fn series_score(&self, game: &Game) -> Option<(u8, u8)> {
+        let series_id = game.playoffs.as_ref()?.series_id;
+        let (h_team, v_team) = (game.h_team.team_id, game.v_team.team_id);
+        let (mut h_wins, mut v_wins) = (0, 0);
+        for x in self.games.iter().filter(|x| {
+            x.status_num == 3
+                && x.start_time_utc <= game.start_time_utc
+                && x.playoffs.as_ref().map(|p| p.series_id) == Some(series_id)
+        }) {
+            match x.winner() {
+                Some(id) if id == h_team => h_wins += 1,
+                Some(id) if id == v_team => v_wins += 1,
+                _ => {}
+            }
+        }
+        Some((h_wins, v_wins))
+    }
 }
 
 impl<'lf, 'de> Deserialize<'de> for Schedule<'lf>
@@ -65,10 +110,80 @@ where
     }
 }
 
+/// Games of a single date from the scoreboard feed, which unlike the
+/// schedule carries the game clock.
+#[derive(Debug)]
+pub struct Scoreboard<'lf> {
+    games: Vec<Game<'lf>>,
+}
+
+fn scoreboard_url(date: &str) -> String {
+    format!("http://data.nba.com/prod/v1/{}/scoreboard.json", date)
+}
+
+impl<'lf> Scoreboard<'lf> {
+    /// Fetches the games of `date`, blocking version of `fetch`.
+    pub fn new(client: &Client, date: &str) -> Result<Self, Error> {
+        let json = Box::leak::<'lf>(Box::new(client.get(scoreboard_url(date)).send()?.text()?));
+
+        Self::from_json(json)
+    }
+
+    /// Fetches the games of `date`, in `yyyymmdd` Eastern time.
+    pub async fn fetch(client: &reqwest::Client, date: &str) -> Result<Self, Error> {
+        let json = Box::leak::<'lf>(Box::new(Self::fetch_json(client, date).await?));
+
+        Self::from_json(json)
+    }
+
+    /// Fetches the json of the scoreboard, to be parsed by `from_json`.
+    pub async fn fetch_json(client: &reqwest::Client, date: &str) -> Result<String, Error> {
+        Ok(client
+            .get(scoreboard_url(date))
+            .send()
+            .await?
+            .text()
+            .await?)
+    }
+
+    /// Parses the json of the scoreboard feed.
+    pub fn from_json(json: &'lf str) -> Result<Self, Error> {
+        Ok(serde_json::from_str::<Scoreboard>(json)?)
+    }
+
+    /// Games of the date, in the order of the feed.
+    pub fn games(&self) -> &[Game<'lf>] {
+        &self.games
+    }
+}
+
+impl<'lf, 'de> Deserialize<'de> for Scoreboard<'lf>
+where
+    'de: 'lf,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(bound(deserialize = "'de: 'lf"))]
+        struct Root<'lf> {
+            games: Vec<Game<'lf>>,
+        }
+
+        let helper = Root::deserialize(deserializer)?;
+
+        Ok(Self {
+            games: helper.games,
+        })
+    }
+}
+
+/// A scheduled game.
 #[derive(Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 #[serde(bound(deserialize = "'de: 'lf"))]
-struct Game<'lf> {
+pub struct Game<'lf> {
     game_id: &'lf str,
     season_stage_id: usize,
     game_url_code: &'lf str,
@@ -82,20 +197,159 @@ struct Game<'lf> {
     is_neutral_venue: bool,
     start_time_eastern: &'lf str,
     is_buzzer_beater: bool,
+    #[serde(default)]
+    clock: &'lf str,
     period: Period,
     playoffs: Option<Playoffs<'lf>>,
     h_team: Team<'lf>,
     v_team: Team<'lf>,
 }
 
+impl<'lf> Game<'lf> {
+    /// Id of the game, used by the boxscore and play by play feeds.
+    pub fn game_id(&self) -> &'lf str {
+        self.game_id
+    }
+
+    /// `1` preseason, `2` regular season, `3` all-star, `4` playoffs.
+    pub fn season_stage_id(&self) -> usize {
+        self.season_stage_id
+    }
+
+    /// `yyyymmdd/VISHOM` code used in nba.com game urls.
+    pub fn game_url_code(&self) -> &'lf str {
+        self.game_url_code
+    }
+
+    /// Tri-code of the home team, taken from the game url code.
+    pub fn h_tri_code(&self) -> &'lf str {
+        let code = self.game_url_code;
+        code.get(code.len().saturating_sub(3)..).unwrap_or("")
+    }
+
+    /// Tri-code of the visitor team, taken from the game url code.
+    pub fn v_tri_code(&self) -> &'lf str {
+        let code = self.game_url_code;
+        code.get(code.len().saturating_sub(6)..code.len().saturating_sub(3))
+            .unwrap_or("")
+    }
+
+    /// `1` scheduled, `2` live, `3` final.
+    pub fn status_num(&self) -> usize {
+        self.status_num
+    }
+
+    /// Reason of a delay, `0` normally.
+    pub fn extended_status_num(&self) -> usize {
+        self.extended_status_num
+    }
+
+    /// Whether the start time is yet to be determined.
+    pub fn is_start_time_tbd(&self) -> bool {
+        self.is_start_time_tbd
+    }
+
+    /// Start time as an ISO 8601 UTC string.
+    pub fn start_time_utc(&self) -> &'lf str {
+        self.start_time_utc
+    }
+
+    /// Start time, `None` if the feed's time is malformed.
+    pub fn start_time(&self) -> Option<DateTime<Utc>> {
+        DateTime::parse_from_rfc3339(self.start_time_utc)
+            .ok()
+            .map(|x| x.with_timezone(&Utc))
+    }
+
+    /// Start date in `yyyymmdd` Eastern time.
+    pub fn start_date_eastern(&self) -> &'lf str {
+        self.start_date_eastern
+    }
+
+    /// Start time in Eastern time, e.g. `7:30 PM ET`.
+    pub fn start_time_eastern(&self) -> &'lf str {
+        self.start_time_eastern
+    }
+
+    /// Whether the game is played on neutral ground.
+    pub fn is_neutral_venue(&self) -> bool {
+        self.is_neutral_venue
+    }
+
+    /// Whether the game was decided by a buzzer beater.
+    pub fn is_buzzer_beater(&self) -> bool {
+        self.is_buzzer_beater
+    }
+
+    /// Game clock of the current period, only in the scoreboard feed and
+    /// empty when the clock is stopped between periods.
+    pub fn clock(&self) -> &'lf str {
+        self.clock
+    }
+
+    /// Current period, the last one once final.
+    pub fn period(&self) -> &Period {
+        &self.period
+    }
+
+    /// Series information, `None` outside of the playoffs.
+    pub fn playoffs(&self) -> Option<&Playoffs<'lf>> {
+        self.playoffs.as_ref()
+    }
+
+    /// The home team.
+    pub fn h_team(&self) -> &Team<'lf> {
+        &self.h_team
+    }
+
+    /// The visiting team.
+    pub fn v_team(&self) -> &Team<'lf> {
+        &self.v_team
+    }
+
+    /// Team id of the winner of a finished game.
+    pub fn winner(&self) -> Option<&'lf str> {
+        if self.status_num != 3 || self.h_team.score == self.v_team.score {
+            None
+        } else if self.h_team.score > self.v_team.score {
+            Some(self.h_team.team_id)
+        } else {
+            Some(self.v_team.team_id)
+        }
+    }
+}
+
+/// A team of a scheduled game.
 #[derive(Debug)]
-struct Team<'lf> {
+pub struct Team<'lf> {
     team_id: &'lf str,
     score: u8,
     win: u8,
     loss: u8,
 }
 
+impl<'lf> Team<'lf> {
+    /// Id of the team.
+    pub fn team_id(&self) -> &'lf str {
+        self.team_id
+    }
+
+    /// Points scored, `0` before tip-off.
+    pub fn score(&self) -> u8 {
+        self.score
+    }
+
+    /// Wins of the team after this game.
+    pub fn win(&self) -> u8 {
+        self.win
+    }
+
+    /// Losses of the team after this game.
+    pub fn loss(&self) -> u8 {
+        self.loss
+    }
+}
+
 impl<'lf, 'de> Deserialize<'de> for Team<'lf>
 where
     'de: 'lf,
@@ -125,18 +379,61 @@ where
     }
 }
 
+/// Playoff series information of a scheduled game.
 #[derive(Debug)]
-struct Playoffs<'lf> {
+pub struct Playoffs<'lf> {
     round_num: &'lf str,
     conf_name: &'lf str,
     series_id: &'lf str,
-    is_series_completed: bool,
-    game_num_in_series: &'lf str,
-    is_if_necessary: bool,
+    completed: bool,
+    num_in_series: &'lf str,
+    if_necessary: bool,
     v_team_seed: u8,
     h_team_seed: u8,
 }
 
+impl<'lf> Playoffs<'lf> {
+    /// Round of the series, from `1` to `4`.
+    pub fn round_num(&self) -> &'lf str {
+        self.round_num
+    }
+
+    /// `East`, `West` or `NBA` for the finals.
+    pub fn conf_name(&self) -> &'lf str {
+        self.conf_name
+    }
+
+    /// Id shared by every game of the series.
+    pub fn series_id(&self) -> &'lf str {
+        self.series_id
+    }
+
+    /// Whether the series is over.
+    pub fn completed(&self) -> bool {
+        self.completed
+    }
+
+    /// Number of the game in the series.
+    pub fn num_in_series(&self) -> &'lf str {
+        self.num_in_series
+    }
+
+    /// Whether the game is only played if the series is not decided yet.
+    pub fn if_necessary(&self) -> bool {
+        self.if_necessary
+    }
+
+    /// Seed of the visiting team.
+    pub fn v_team_seed(&self) -> u8 {
+        self.v_team_seed
+    }
+
+    /// Seed of the home team.
+    pub fn h_team_seed(&self) -> u8 {
+        self.h_team_seed
+    }
+}
+
 impl<'lf, 'de> Deserialize<'de> for Playoffs<'lf>
 where
     'de: 'lf,
@@ -171,9 +468,9 @@ where
             round_num: helper.round_num,
             conf_name: helper.conf_name,
             series_id: helper.series_id,
-            is_series_completed: helper.is_series_completed,
-            game_num_in_series: helper.game_num_in_series,
-            is_if_necessary: helper.is_if_necessary,
+            completed: helper.is_series_completed,
+            num_in_series: helper.game_num_in_series,
+            if_necessary: helper.is_if_necessary,
             v_team_seed: helper.v_team.seed_num.parse().unwrap_or(0),
             h_team_seed: helper.h_team.seed_num.parse().unwrap_or(0),
         })
@@ -0,0 +1,539 @@
+use crate::schedule::{Game, Schedule};
+use crate::teams::{self, Conference, Division, Team};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::fmt;
+
+/// Win-loss record.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Record {
+    /// Games won.
+    pub wins: u32,
+    /// Games lost.
+    pub losses: u32,
+}
+
+impl Record {
+    /// Games played.
+    pub fn games(&self) -> u32 {
+        self.wins + self.losses
+    }
+
+    /// Winning percentage, `0` without any game played.
+    pub fn pct(&self) -> f64 {
+        if self.games() == 0 {
+            0.0
+        } else {
+            self.wins as f64 / self.games() as f64
+        }
+    }
+
+    fn add(&mut self, won: bool) {
+        if won {
+            self.wins += 1;
+        } else {
+            self.losses += 1;
+        }
+    }
+}
+
+impl std::ops::Add for Record {
+    type Output = Record;
+
+    fn add(self, other: Record) -> Record {
+        Record {
+            wins: self.wins + other.wins,
+            losses: self.losses + other.losses,
+        }
+    }
+}
+
+impl fmt::Display for Record {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.wins, self.losses)
+    }
+}
+
+/// Consecutive wins or losses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Streak {
+    /// Consecutive wins.
+    Won(u32),
+    /// Consecutive losses.
+    Lost(u32),
+}
+
+impl Streak {
+    /// Current streak of chronological `results`, `true` being a win.
+    pub fn from_results(results: &[bool]) -> Option<Self> {
+        let last = *results.last()?;
+        let count = results.iter().rev().take_while(|&&x| x == last).count() as u32;
+        Some(if last {
+            Streak::Won(count)
+        } else {
+            Streak::Lost(count)
+        })
+    }
+}
+
+impl fmt::Display for Streak {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Streak::Won(n) => write!(f, "W{}", n),
+            Streak::Lost(n) => write!(f, "L{}", n),
+        }
+    }
+}
+
+/// Regular season standing of a team.
+#[derive(Debug, Clone)]
+pub struct Standing {
+    /// The team.
+    pub team: &'static Team,
+    /// Overall record.
+    pub record: Record,
+    /// Record at home.
+    pub home: Record,
+    /// Record on the road.
+    pub away: Record,
+    /// Record against division opponents.
+    pub division: Record,
+    /// Record against conference opponents.
+    pub conference: Record,
+    /// Record of the last ten games.
+    pub last_10: Record,
+    /// Current streak, `None` before the first game.
+    pub streak: Option<Streak>,
+    /// Points scored minus points allowed.
+    pub point_diff: i32,
+    /// Games behind the leader of the table the team was ranked in.
+    pub games_behind: f32,
+    head_to_head: HashMap<&'static str, Record>,
+}
+
+impl Standing {
+    fn new(team: &'static Team) -> Self {
+        Standing {
+            team,
+            record: Record::default(),
+            home: Record::default(),
+            away: Record::default(),
+            division: Record::default(),
+            conference: Record::default(),
+            last_10: Record::default(),
+            streak: None,
+            point_diff: 0,
+            games_behind: 0.0,
+            head_to_head: HashMap::new(),
+        }
+    }
+
+    /// Record against the team `team_id`.
+    pub fn against(&self, team_id: &str) -> Record {
+        self.head_to_head.get(team_id).copied().unwrap_or_default()
+    }
+}
+
+/// Conference and division tables derived from the finished regular season
+/// games of a schedule.
+#[derive(Debug)]
+pub struct Standings {
+    east: Vec<Standing>,
+    west: Vec<Standing>,
+    divisions: Vec<(Division, Vec<Standing>)>,
+}
+
+impl Standings {
+    /// Ranks every team from the finished regular season games of `schedule`.
+    pub fn new(schedule: &Schedule) -> Self {
+        let mut standings: Vec<Standing> = teams::TEAMS.iter().map(Standing::new).collect();
+        let mut results: Vec<Vec<bool>> = vec![Vec::new(); standings.len()];
+        let index = |team_id: &str| teams::TEAMS.iter().position(|x| x.team_id == team_id);
+
+        let mut games: Vec<_> = schedule
+            .games()
+            .iter()
+            .filter(|x| x.season_stage_id() == 2 && x.status_num() == 3)
+            .collect();
+        games.sort_by_key(|x| x.start_time_utc());
+
+        for game in games {
+            let (h, v) = (game.h_team(), game.v_team());
+            let (h_idx, v_idx) = match (index(h.team_id()), index(v.team_id())) {
+                (Some(h_idx), Some(v_idx)) => (h_idx, v_idx),
+                _ => continue,
+            };
+            let h_won = h.score() > v.score();
+            let diff = h.score() as i32 - v.score() as i32;
+            for &(idx, opp_idx, won, home, diff) in &[
+                (h_idx, v_idx, h_won, true, diff),
+                (v_idx, h_idx, !h_won, false, -diff),
+            ] {
+                let opponent = &teams::TEAMS[opp_idx];
+                let standing = &mut standings[idx];
+                standing.record.add(won);
+                if home {
+                    standing.home.add(won);
+                } else {
+                    standing.away.add(won);
+                }
+                if opponent.division == standing.team.division {
+                    standing.division.add(won);
+                }
+                if opponent.conference() == standing.team.conference() {
+                    standing.conference.add(won);
+                }
+                standing
+                    .head_to_head
+                    .entry(opponent.team_id)
+                    .or_default()
+                    .add(won);
+                standing.point_diff += diff;
+                results[idx].push(won);
+            }
+        }
+
+        for (standing, results) in standings.iter_mut().zip(results.iter()) {
+            for &won in results.iter().rev().take(10) {
+                standing.last_10.add(won);
+            }
+            standing.streak = Streak::from_results(results);
+        }
+
+        let divisions: Vec<(Division, Vec<Standing>)> = Division::ALL
+            .iter()
+            .map(|&division| {
+                let table = standings
+                    .iter()
+                    .filter(|x| x.team.division == division)
+                    .cloned()
+                    .collect();
+                (division, rank(table, &[]))
+            })
+            .collect();
+        let leaders: Vec<&str> = divisions
+            .iter()
+            .filter_map(|(_, table)| table.first().map(|x| x.team.team_id))
+            .collect();
+        let conference = |conference: Conference| {
+            let table = standings
+                .iter()
+                .filter(|x| x.team.conference() == conference)
+                .cloned()
+                .collect();
+            rank(table, &leaders)
+        };
+
+        Standings {
+            east: conference(Conference::East),
+            west: conference(Conference::West),
+            divisions,
+        }
+    }
+
+    /// Ranked conference table, games behind the conference leader.
+    pub fn conference(&self, conference: Conference) -> &[Standing] {
+        match conference {
+            Conference::East => &self.east,
+            Conference::West => &self.west,
+        }
+    }
+
+    /// Ranked division table, games behind the division leader.
+    pub fn division(&self, division: Division) -> &[Standing] {
+        self.divisions
+            .iter()
+            .find(|(x, _)| *x == division)
+            .map(|(_, table)| table.as_slice())
+            .unwrap_or(&[])
+    }
+}
+
+/// A game of a team's schedule.
+#[derive(Debug)]
+pub struct TeamGame<'a, 'lf> {
+    /// The game.
+    pub game: &'a Game<'lf>,
+    /// Whether the team played at home.
+    pub home: bool,
+    /// Whether the team won, `None` until the game is final.
+    pub won: Option<bool>,
+    /// Record of the team in the game's season stage after the game.
+    pub record: Record,
+    /// Streak of the team after the game.
+    pub streak: Option<Streak>,
+}
+
+impl<'a, 'lf> TeamGame<'a, 'lf> {
+    /// Team id of the opponent.
+    pub fn opponent(&self) -> &'lf str {
+        if self.home {
+            self.game.v_team().team_id()
+        } else {
+            self.game.h_team().team_id()
+        }
+    }
+}
+
+/// Every game of `team_id` in chronological order, with the running record
+/// of each season stage.
+pub fn team_schedule<'a, 'lf>(
+    schedule: &'a Schedule<'lf>,
+    team_id: &str,
+) -> Vec<TeamGame<'a, 'lf>> {
+    let mut games: Vec<&Game> = schedule
+        .games()
+        .iter()
+        .filter(|x| x.h_team().team_id() == team_id || x.v_team().team_id() == team_id)
+        .collect();
+    games.sort_by_key(|x| x.start_time_utc());
+
+    let mut stage = None;
+    let mut record = Record::default();
+    let mut results = Vec::new();
+    games
+        .into_iter()
+        .map(|game| {
+            if stage != Some(game.season_stage_id()) {
+                stage = Some(game.season_stage_id());
+                record = Record::default();
+                results.clear();
+            }
+            let won = game.winner().map(|x| x == team_id);
+            if let Some(won) = won {
+                record.add(won);
+                results.push(won);
+            }
+            TeamGame {
+                game,
+                home: game.h_team().team_id() == team_id,
+                won,
+                record,
+                streak: Streak::from_results(&results),
+            }
+        })
+        .collect()
+}
+
+fn cmp_pct(a: Record, b: Record) -> Ordering {
+    a.pct().partial_cmp(&b.pct()).unwrap_or(Ordering::Equal)
+}
+
+/// Formats a percentage the way standings do, `.650` or `1.000`.
+pub fn format_pct(pct: f64) -> String {
+    let pct = format!("{:.3}", pct);
+    pct.strip_prefix('0').map(str::to_string).unwrap_or(pct)
+}
+
+/// Games behind with one decimal, `-` for the first team.
+pub fn format_games_behind(games_behind: f32) -> String {
+    if games_behind == 0.0 {
+        "-".to_string()
+    } else {
+        format!("{:.1}", games_behind)
+    }
+}
+
+/// Sorts `table` by winning percentage, applying tie-breakers, and sets the
+/// games behind the first team.
+fn rank(mut table: Vec<Standing>, division_leaders: &[&str]) -> Vec<Standing> {
+    table.sort_by(|a, b| cmp_pct(b.record, a.record));
+
+    let mut start = 0;
+    while start < table.len() {
+        let pct = table[start].record.pct();
+        let end = start
+            + table[start..]
+                .iter()
+                .take_while(|x| x.record.pct() == pct)
+                .count();
+        if end - start > 1 {
+            break_ties(&mut table[start..end], division_leaders);
+        }
+        start = end;
+    }
+
+    if let Some(leader) = table.first().map(|x| x.record) {
+        for standing in table.iter_mut() {
+            standing.games_behind = ((leader.wins as f32 - standing.record.wins as f32)
+                + (standing.record.losses as f32 - leader.losses as f32))
+                / 2.0;
+        }
+    }
+    table
+}
+
+/// Orders teams with the same winning percentage. Two tied teams are
+/// separated by their head-to-head record first and division leaders come
+/// second; ties of three or more teams put division leaders first and then
+/// look at the record between the tied teams. The division record when they
+/// share a division, the conference record and the point differential
+/// follow in both cases.
+fn break_ties(tied: &mut [Standing], division_leaders: &[&str]) {
+    let ids: Vec<&str> = tied.iter().map(|x| x.team.team_id).collect();
+    let same_division = tied
+        .iter()
+        .all(|x| x.team.division == tied[0].team.division);
+    let is_leader = |x: &Standing| division_leaders.contains(&x.team.team_id);
+    let between = |x: &Standing| {
+        ids.iter()
+            .fold(Record::default(), |acc, id| acc + x.against(id))
+    };
+    let leader = |a: &Standing, b: &Standing| is_leader(b).cmp(&is_leader(a));
+    let head_to_head = |a: &Standing, b: &Standing| cmp_pct(between(b), between(a));
+    let two_teams = tied.len() == 2;
+
+    tied.sort_by(|a, b| {
+        if two_teams {
+            head_to_head(a, b).then_with(|| leader(a, b))
+        } else {
+            leader(a, b).then_with(|| head_to_head(a, b))
+        }
+        .then_with(|| {
+            if same_division {
+                cmp_pct(b.division, a.division)
+            } else {
+                Ordering::Equal
+            }
+        })
+        .then_with(|| cmp_pct(b.conference, a.conference))
+        .then_with(|| b.point_diff.cmp(&a.point_diff))
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A finished regular season game, `n` orders the games.
+    fn game(n: usize, home: &str, visitor: &str, h_score: u8, v_score: u8) -> String {
+        let team_id = |tri_code: &str| {
+            teams::TEAMS
+                .iter()
+                .find(|x| x.tri_code == tri_code)
+                .unwrap()
+                .team_id
+        };
+        format!(
+            r#"{{"gameId":"{n}","seasonStageId":2,"gameUrlCode":"","statusNum":3,
+            "extendedStatusNum":0,"isStartTimeTBD":false,
+            "startTimeUTC":"2021-01-{n:02}T00:00:00.000Z","startDateEastern":"",
+            "isNeutralVenue":false,"startTimeEastern":"","isBuzzerBeater":false,
+            "period":{{"current":4,"type":0,"maxRegular":4}},
+            "hTeam":{{"teamId":"{h}","score":"{hs}","win":"0","loss":"0"}},
+            "vTeam":{{"teamId":"{v}","score":"{vs}","win":"0","loss":"0"}}}}"#,
+            n = n,
+            h = team_id(home),
+            v = team_id(visitor),
+            hs = h_score,
+            vs = v_score,
+        )
+    }
+
+    /// Schedule json of `games` given as `(winner, loser)`, the winner at
+    /// home winning by ten.
+    fn schedule(games: &[(&str, &str)]) -> String {
+        let games: Vec<String> = games
+            .iter()
+            .enumerate()
+            .map(|(n, (winner, loser))| game(n + 1, winner, loser, 110, 100))
+            .collect();
+        format!(r#"{{"league":{{"standard":[{}]}}}}"#, games.join(","))
+    }
+
+    fn order(table: &[Standing], count: usize) -> Vec<&str> {
+        table.iter().take(count).map(|x| x.team.tri_code).collect()
+    }
+
+    #[test]
+    fn rank_by_record() {
+        let json = schedule(&[("BOS", "NYK"), ("BOS", "BKN"), ("NYK", "BKN")]);
+        let schedule = serde_json::from_str::<Schedule>(&json).unwrap();
+        let standings = Standings::new(&schedule);
+        let atlantic = standings.division(Division::Atlantic);
+
+        // BKN ties PHI and TOR, who have not played, on percentage and falls
+        // behind them on point differential.
+        assert_eq!(order(atlantic, 2), ["BOS", "NYK"]);
+        assert_eq!(atlantic[4].team.tri_code, "BKN");
+        assert_eq!(atlantic[0].record, Record { wins: 2, losses: 0 });
+        assert_eq!(atlantic[0].point_diff, 20);
+        assert_eq!(atlantic[0].streak, Some(Streak::Won(2)));
+        assert_eq!(
+            atlantic[1].against("1610612751"),
+            Record { wins: 1, losses: 0 }
+        );
+    }
+
+    #[test]
+    fn games_behind() {
+        let json = schedule(&[("CLE", "BOS"), ("BOS", "NYK"), ("BOS", "BKN")]);
+        let schedule = serde_json::from_str::<Schedule>(&json).unwrap();
+        let standings = Standings::new(&schedule);
+        let atlantic = standings.division(Division::Atlantic);
+        let games_behind = |tri_code: &str| {
+            atlantic
+                .iter()
+                .find(|x| x.team.tri_code == tri_code)
+                .unwrap()
+                .games_behind
+        };
+
+        assert_eq!(games_behind("BOS"), 0.0);
+        assert_eq!(games_behind("PHI"), 0.5);
+        assert_eq!(games_behind("NYK"), 1.0);
+        assert_eq!(format_games_behind(games_behind("BOS")), "-");
+        assert_eq!(format_games_behind(games_behind("PHI")), "0.5");
+    }
+
+    #[test]
+    fn two_team_tie_head_to_head_first() {
+        // BOS leads the Atlantic, CLE trails CHI in the Central, both are
+        // 2-1 and CLE won their game.
+        let json = schedule(&[
+            ("CLE", "BOS"),
+            ("BOS", "NYK"),
+            ("BOS", "BKN"),
+            ("CHI", "CLE"),
+            ("CLE", "MIL"),
+        ]);
+        let schedule = serde_json::from_str::<Schedule>(&json).unwrap();
+        let standings = Standings::new(&schedule);
+
+        assert_eq!(
+            order(standings.conference(Conference::East), 3),
+            ["CHI", "CLE", "BOS"]
+        );
+    }
+
+    #[test]
+    fn multi_team_tie_division_leaders_first() {
+        // DEN, LAL and DAL are 2-2. DEN and LAL lead their division, DAL
+        // trails HOU but won both games against the other two.
+        let json = schedule(&[
+            ("DAL", "DEN"),
+            ("DAL", "LAL"),
+            ("HOU", "DAL"),
+            ("HOU", "DAL"),
+            ("DEN", "LAL"),
+            ("DEN", "POR"),
+            ("BOS", "DEN"),
+            ("LAL", "BOS"),
+            ("LAL", "BKN"),
+        ]);
+        let schedule = serde_json::from_str::<Schedule>(&json).unwrap();
+        let standings = Standings::new(&schedule);
+
+        assert_eq!(
+            order(standings.conference(Conference::West), 4),
+            ["HOU", "DEN", "LAL", "DAL"]
+        );
+    }
+
+    #[test]
+    fn pct_format() {
+        assert_eq!(format_pct(0.65), ".650");
+        assert_eq!(format_pct(1.0), "1.000");
+        assert_eq!(format_pct(0.0), ".000");
+    }
+}
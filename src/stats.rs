@@ -0,0 +1,332 @@
+use crate::utils::{Player, Totals};
+
+/// Minutes of a `MM:SS` or `MM` clock, with the seconds as a fraction.
+pub fn parse_minutes(s: &str) -> f32 {
+    let mut parts = s.trim().splitn(2, ':');
+    let minutes = parts.next().and_then(|x| x.parse::<f32>().ok());
+    let seconds = parts.next().and_then(|x| x.parse::<f32>().ok());
+    minutes.unwrap_or(0.0) + seconds.unwrap_or(0.0) / 60.0
+}
+
+/// A percentage like `45.5`, `0` when empty.
+pub fn parse_pct(s: &str) -> f32 {
+    s.trim().parse().unwrap_or(0.0)
+}
+
+/// A plus/minus like `+5` or `-3`, `0` when empty.
+pub fn parse_plus_minus(s: &str) -> i16 {
+    s.trim().parse().unwrap_or(0)
+}
+
+fn parse_count(s: &str) -> u16 {
+    s.trim().parse().unwrap_or(0)
+}
+
+/// Numeric stats of a player or a team, parsed from the strings of the
+/// boxscore feed. Missing stats are `0`.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Stats {
+    /// Minutes played.
+    pub minutes: f32,
+    /// Points scored.
+    pub points: u16,
+    /// Field goals made.
+    pub fgm: u16,
+    /// Field goals attempted.
+    pub fga: u16,
+    /// Field goal percentage.
+    pub fgp: f32,
+    /// Free throws made.
+    pub ftm: u16,
+    /// Free throws attempted.
+    pub fta: u16,
+    /// Free throw percentage.
+    pub ftp: f32,
+    /// Three pointers made.
+    pub tpm: u16,
+    /// Three pointers attempted.
+    pub tpa: u16,
+    /// Three point percentage.
+    pub tpp: f32,
+    /// Offensive rebounds.
+    pub off_reb: u16,
+    /// Defensive rebounds.
+    pub def_reb: u16,
+    /// Total rebounds.
+    pub tot_reb: u16,
+    /// Assists.
+    pub assists: u16,
+    /// Personal fouls.
+    pub p_fouls: u16,
+    /// Steals.
+    pub steals: u16,
+    /// Turnovers.
+    pub turnovers: u16,
+    /// Blocked shots.
+    pub blocks: u16,
+    /// Shots blocked by the other team.
+    pub blocks_against: u16,
+    /// Point differential while on the court.
+    pub plus_minus: i16,
+}
+
+impl<'a> From<&Player<'a>> for Stats {
+    fn from(p: &Player<'a>) -> Self {
+        let count = |x: Option<&str>| x.map_or(0, parse_count);
+        let pct = |x: Option<&str>| x.map_or(0.0, parse_pct);
+        Stats {
+            minutes: p.min.map_or(0.0, parse_minutes),
+            points: count(p.points),
+            fgm: count(p.fgm),
+            fga: count(p.fga),
+            fgp: pct(p.fgp),
+            ftm: count(p.ftm),
+            fta: count(p.fta),
+            ftp: pct(p.ftp),
+            tpm: count(p.tpm),
+            tpa: count(p.tpa),
+            tpp: pct(p.tpp),
+            off_reb: count(p.off_reb),
+            def_reb: count(p.def_reb),
+            tot_reb: count(p.tot_reb),
+            assists: count(p.assists),
+            p_fouls: count(p.p_fouls),
+            steals: count(p.steals),
+            turnovers: count(p.turnovers),
+            blocks: count(p.blocks),
+            blocks_against: count(p.blocks_against),
+            plus_minus: p.plus_minus.map_or(0, parse_plus_minus),
+        }
+    }
+}
+
+impl<'a> From<&Totals<'a>> for Stats {
+    fn from(t: &Totals<'a>) -> Self {
+        Stats {
+            minutes: parse_minutes(t.min),
+            points: parse_count(t.points),
+            fgm: parse_count(t.fgm),
+            fga: parse_count(t.fga),
+            fgp: parse_pct(t.fgp),
+            ftm: parse_count(t.ftm),
+            fta: parse_count(t.fta),
+            ftp: parse_pct(t.ftp),
+            tpm: parse_count(t.tpm),
+            tpa: parse_count(t.tpa),
+            tpp: parse_pct(t.tpp),
+            off_reb: parse_count(t.off_reb),
+            def_reb: parse_count(t.def_reb),
+            tot_reb: parse_count(t.tot_reb),
+            assists: parse_count(t.assists),
+            p_fouls: parse_count(t.p_fouls),
+            steals: parse_count(t.steals),
+            turnovers: parse_count(t.turnovers),
+            blocks: parse_count(t.blocks),
+            // Not in the team totals of the feed
+            blocks_against: 0,
+            plus_minus: parse_plus_minus(t.plus_minus),
+        }
+    }
+}
+
+impl Stats {
+    /// Possessions used by shots and turnovers, free throws weighted by 0.44.
+    fn plays(&self) -> f32 {
+        self.fga as f32 + 0.44 * self.fta as f32 + self.turnovers as f32
+    }
+
+    /// Estimated possessions of a team.
+    pub fn possessions(&self) -> f32 {
+        (self.plays() - self.off_reb as f32).max(0.0)
+    }
+
+    /// True shooting percentage, `None` without any shot.
+    pub fn true_shooting(&self) -> Option<f32> {
+        let attempts = self.fga as f32 + 0.44 * self.fta as f32;
+        if attempts == 0.0 {
+            return None;
+        }
+        Some(100.0 * self.points as f32 / (2.0 * attempts))
+    }
+
+    /// Effective field goal percentage, `None` without any field goal attempt.
+    pub fn effective_fg(&self) -> Option<f32> {
+        if self.fga == 0 {
+            return None;
+        }
+        Some(100.0 * (self.fgm as f32 + 0.5 * self.tpm as f32) / self.fga as f32)
+    }
+
+    /// Assists per turnover, `None` without any turnover.
+    pub fn assist_turnover(&self) -> Option<f32> {
+        if self.turnovers == 0 {
+            return None;
+        }
+        Some(self.assists as f32 / self.turnovers as f32)
+    }
+
+    /// John Hollinger's game score.
+    pub fn game_score(&self) -> f32 {
+        self.points as f32 + 0.4 * self.fgm as f32
+            - 0.7 * self.fga as f32
+            - 0.4 * (self.fta as f32 - self.ftm as f32)
+            + 0.7 * self.off_reb as f32
+            + 0.3 * self.def_reb as f32
+            + self.steals as f32
+            + 0.7 * self.assists as f32
+            + 0.7 * self.blocks as f32
+            - 0.4 * self.p_fouls as f32
+            - self.turnovers as f32
+    }
+
+    /// Estimated share of the team's possessions used while on the court,
+    /// `None` when the player or the team has not played.
+    pub fn usage(&self, team: &Stats) -> Option<f32> {
+        let team_plays = team.plays();
+        if self.minutes == 0.0 || team.minutes == 0.0 || team_plays == 0.0 {
+            return None;
+        }
+        Some(100.0 * self.plays() * (team.minutes / 5.0) / (self.minutes * team_plays))
+    }
+}
+
+/// Dean Oliver's four factors of a team's offense, all in percent but the
+/// free throw rate.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct FourFactors {
+    /// Effective field goal percentage.
+    pub efg: f32,
+    /// Turnovers per hundred plays.
+    pub tov: f32,
+    /// Share of the available offensive rebounds.
+    pub orb: f32,
+    /// Free throws made per field goal attempt.
+    pub ft_rate: f32,
+}
+
+impl FourFactors {
+    /// Four factors of `team` against `opponent`.
+    pub fn new(team: &Stats, opponent: &Stats) -> Self {
+        let ratio = |x: f32, y: f32| if y == 0.0 { 0.0 } else { x / y };
+        FourFactors {
+            efg: team.effective_fg().unwrap_or(0.0),
+            tov: 100.0 * ratio(team.turnovers as f32, team.plays()),
+            orb: 100.0
+                * ratio(
+                    team.off_reb as f32,
+                    (team.off_reb + opponent.def_reb) as f32,
+                ),
+            ft_rate: ratio(team.ftm as f32, team.fga as f32),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Kobe Bryant's 81 point game against Toronto, January 22 2006.
+    fn kobe_81() -> Stats {
+        Stats {
+            minutes: parse_minutes("41:56"),
+            points: 81,
+            fgm: 28,
+            fga: 46,
+            ftm: 18,
+            fta: 20,
+            tpm: 7,
+            tpa: 13,
+            off_reb: 2,
+            def_reb: 4,
+            tot_reb: 6,
+            assists: 2,
+            p_fouls: 1,
+            steals: 3,
+            turnovers: 3,
+            blocks: 1,
+            ..Stats::default()
+        }
+    }
+
+    fn round(x: f32) -> f32 {
+        (x * 10.0).round() / 10.0
+    }
+
+    #[test]
+    fn parsing() {
+        assert_eq!(parse_minutes("41:56"), 41.0 + 56.0 / 60.0);
+        assert_eq!(parse_minutes("240"), 240.0);
+        assert_eq!(parse_minutes(""), 0.0);
+        assert_eq!(parse_plus_minus("+5"), 5);
+        assert_eq!(parse_plus_minus("-3"), -3);
+        assert_eq!(parse_pct(""), 0.0);
+    }
+
+    #[test]
+    fn shooting() {
+        let kobe = kobe_81();
+        assert_eq!(kobe.true_shooting().map(round), Some(73.9));
+        assert_eq!(kobe.effective_fg().map(round), Some(68.5));
+        assert_eq!(round(kobe.game_score()), 63.5);
+        assert_eq!(kobe.assist_turnover().map(round), Some(0.7));
+        assert_eq!(Stats::default().true_shooting(), None);
+        assert_eq!(Stats::default().effective_fg(), None);
+    }
+
+    #[test]
+    fn usage() {
+        let team = Stats {
+            minutes: 240.0,
+            fga: 80,
+            fta: 25,
+            turnovers: 14,
+            ..Stats::default()
+        };
+        // 16 + 0.44 * 5 + 3 plays in 24 minutes, against the 105 plays of
+        // the team in 48, is 21.2 / 52.5 of the team's plays on the court
+        let player = Stats {
+            minutes: 24.0,
+            fga: 16,
+            fta: 5,
+            turnovers: 3,
+            ..Stats::default()
+        };
+        assert_eq!(team.plays(), 105.0);
+        assert_eq!(player.usage(&team).map(round), Some(40.4));
+        assert_eq!(Stats::default().usage(&team), None);
+    }
+
+    #[test]
+    fn four_factors() {
+        let team = Stats {
+            fgm: 40,
+            fga: 85,
+            tpm: 12,
+            ftm: 18,
+            fta: 25,
+            off_reb: 10,
+            def_reb: 35,
+            turnovers: 12,
+            ..Stats::default()
+        };
+        let opponent = Stats {
+            off_reb: 8,
+            def_reb: 30,
+            ..Stats::default()
+        };
+        let factors = FourFactors::new(&team, &opponent);
+        // (40 + 6) / 85
+        assert_eq!(round(factors.efg), 54.1);
+        // 12 / (85 + 11 + 12)
+        assert_eq!(round(factors.tov), 11.1);
+        // 10 / (10 + 30)
+        assert_eq!(factors.orb, 25.0);
+        // 18 / 85
+        assert_eq!((factors.ft_rate * 1000.0).round(), 212.0);
+        assert_eq!(team.possessions(), 98.0);
+        assert_eq!(
+            FourFactors::new(&Stats::default(), &Stats::default()),
+            FourFactors::default()
+        );
+    }
+}
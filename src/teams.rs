@@ -0,0 +1,403 @@
+use std::fmt;
+
+/// A conference of the league.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Conference {
+    /// The Eastern conference.
+    East,
+    /// The Western conference.
+    West,
+}
+
+impl fmt::Display for Conference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Conference::East => f.write_str("East"),
+            Conference::West => f.write_str("West"),
+        }
+    }
+}
+
+/// A division of a conference.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Division {
+    /// Atlantic division of the East.
+    Atlantic,
+    /// Central division of the East.
+    Central,
+    /// Southeast division of the East.
+    Southeast,
+    /// Northwest division of the West.
+    Northwest,
+    /// Pacific division of the West.
+    Pacific,
+    /// Southwest division of the West.
+    Southwest,
+}
+
+impl Division {
+    /// Every division, Eastern ones first.
+    pub const ALL: [Division; 6] = [
+        Division::Atlantic,
+        Division::Central,
+        Division::Southeast,
+        Division::Northwest,
+        Division::Pacific,
+        Division::Southwest,
+    ];
+
+    /// Conference of the division.
+    pub fn conference(self) -> Conference {
+        match self {
+            Division::Atlantic | Division::Central | Division::Southeast => Conference::East,
+            Division::Northwest | Division::Pacific | Division::Southwest => Conference::West,
+        }
+    }
+}
+
+impl fmt::Display for Division {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Division::Atlantic => f.write_str("Atlantic"),
+            Division::Central => f.write_str("Central"),
+            Division::Southeast => f.write_str("Southeast"),
+            Division::Northwest => f.write_str("Northwest"),
+            Division::Pacific => f.write_str("Pacific"),
+            Division::Southwest => f.write_str("Southwest"),
+        }
+    }
+}
+
+/// A franchise of the league. The feeds only carry team ids, this table
+/// fills in everything else.
+#[derive(Debug)]
+pub struct Team {
+    /// Id of the team in the feeds.
+    pub team_id: &'static str,
+    /// Three letter code, e.g. `LAL`.
+    pub tri_code: &'static str,
+    /// City, e.g. `Los Angeles`.
+    pub city: &'static str,
+    /// Nickname, e.g. `Lakers`.
+    pub nickname: &'static str,
+    /// Division of the team.
+    pub division: Division,
+    /// Official primary color as `0xRRGGBB`.
+    pub primary: u32,
+    /// Official secondary color as `0xRRGGBB`.
+    pub secondary: u32,
+}
+
+impl Team {
+    const fn new(
+        team_id: &'static str,
+        tri_code: &'static str,
+        city: &'static str,
+        nickname: &'static str,
+        division: Division,
+        primary: u32,
+        secondary: u32,
+    ) -> Self {
+        Team {
+            team_id,
+            tri_code,
+            city,
+            nickname,
+            division,
+            primary,
+            secondary,
+        }
+    }
+
+    /// Conference of the team.
+    pub fn conference(&self) -> Conference {
+        self.division.conference()
+    }
+}
+
+/// The thirty teams of the league.
+pub static TEAMS: [Team; 30] = [
+    Team::new(
+        "1610612737",
+        "ATL",
+        "Atlanta",
+        "Hawks",
+        Division::Southeast,
+        0xE03A3E,
+        0xC1D32F,
+    ),
+    Team::new(
+        "1610612738",
+        "BOS",
+        "Boston",
+        "Celtics",
+        Division::Atlantic,
+        0x007A33,
+        0xBA9653,
+    ),
+    Team::new(
+        "1610612739",
+        "CLE",
+        "Cleveland",
+        "Cavaliers",
+        Division::Central,
+        0x860038,
+        0xFDBB30,
+    ),
+    Team::new(
+        "1610612740",
+        "NOP",
+        "New Orleans",
+        "Pelicans",
+        Division::Southwest,
+        0x0C2340,
+        0xC8102E,
+    ),
+    Team::new(
+        "1610612741",
+        "CHI",
+        "Chicago",
+        "Bulls",
+        Division::Central,
+        0xCE1141,
+        0x000000,
+    ),
+    Team::new(
+        "1610612742",
+        "DAL",
+        "Dallas",
+        "Mavericks",
+        Division::Southwest,
+        0x00538C,
+        0xB8C4CA,
+    ),
+    Team::new(
+        "1610612743",
+        "DEN",
+        "Denver",
+        "Nuggets",
+        Division::Northwest,
+        0x0E2240,
+        0xFEC524,
+    ),
+    Team::new(
+        "1610612744",
+        "GSW",
+        "Golden State",
+        "Warriors",
+        Division::Pacific,
+        0x1D428A,
+        0xFFC72C,
+    ),
+    Team::new(
+        "1610612745",
+        "HOU",
+        "Houston",
+        "Rockets",
+        Division::Southwest,
+        0xCE1141,
+        0xC4CED4,
+    ),
+    Team::new(
+        "1610612746",
+        "LAC",
+        "LA",
+        "Clippers",
+        Division::Pacific,
+        0xC8102E,
+        0x1D428A,
+    ),
+    Team::new(
+        "1610612747",
+        "LAL",
+        "Los Angeles",
+        "Lakers",
+        Division::Pacific,
+        0x552583,
+        0xFDB927,
+    ),
+    Team::new(
+        "1610612748",
+        "MIA",
+        "Miami",
+        "Heat",
+        Division::Southeast,
+        0x98002E,
+        0xF9A01B,
+    ),
+    Team::new(
+        "1610612749",
+        "MIL",
+        "Milwaukee",
+        "Bucks",
+        Division::Central,
+        0x00471B,
+        0xEEE1C6,
+    ),
+    Team::new(
+        "1610612750",
+        "MIN",
+        "Minnesota",
+        "Timberwolves",
+        Division::Northwest,
+        0x0C2340,
+        0x78BE20,
+    ),
+    Team::new(
+        "1610612751",
+        "BKN",
+        "Brooklyn",
+        "Nets",
+        Division::Atlantic,
+        0x000000,
+        0xFFFFFF,
+    ),
+    Team::new(
+        "1610612752",
+        "NYK",
+        "New York",
+        "Knicks",
+        Division::Atlantic,
+        0x006BB6,
+        0xF58426,
+    ),
+    Team::new(
+        "1610612753",
+        "ORL",
+        "Orlando",
+        "Magic",
+        Division::Southeast,
+        0x0077C0,
+        0xC4CED4,
+    ),
+    Team::new(
+        "1610612754",
+        "IND",
+        "Indiana",
+        "Pacers",
+        Division::Central,
+        0x002D62,
+        0xFDBB30,
+    ),
+    Team::new(
+        "1610612755",
+        "PHI",
+        "Philadelphia",
+        "76ers",
+        Division::Atlantic,
+        0x006BB6,
+        0xED174C,
+    ),
+    Team::new(
+        "1610612756",
+        "PHX",
+        "Phoenix",
+        "Suns",
+        Division::Pacific,
+        0x1D1160,
+        0xE56020,
+    ),
+    Team::new(
+        "1610612757",
+        "POR",
+        "Portland",
+        "Trail Blazers",
+        Division::Northwest,
+        0xE03A3E,
+        0x000000,
+    ),
+    Team::new(
+        "1610612758",
+        "SAC",
+        "Sacramento",
+        "Kings",
+        Division::Pacific,
+        0x5A2D81,
+        0x63727A,
+    ),
+    Team::new(
+        "1610612759",
+        "SAS",
+        "San Antonio",
+        "Spurs",
+        Division::Southwest,
+        0xC4CED4,
+        0x000000,
+    ),
+    Team::new(
+        "1610612760",
+        "OKC",
+        "Oklahoma City",
+        "Thunder",
+        Division::Northwest,
+        0x007AC1,
+        0xEF3B24,
+    ),
+    Team::new(
+        "1610612761",
+        "TOR",
+        "Toronto",
+        "Raptors",
+        Division::Atlantic,
+        0xCE1141,
+        0x000000,
+    ),
+    Team::new(
+        "1610612762",
+        "UTA",
+        "Utah",
+        "Jazz",
+        Division::Northwest,
+        0x002B5C,
+        0xF9A01B,
+    ),
+    Team::new(
+        "1610612763",
+        "MEM",
+        "Memphis",
+        "Grizzlies",
+        Division::Southwest,
+        0x5D76A9,
+        0x12173F,
+    ),
+    Team::new(
+        "1610612764",
+        "WAS",
+        "Washington",
+        "Wizards",
+        Division::Southeast,
+        0x002B5C,
+        0xE31837,
+    ),
+    Team::new(
+        "1610612765",
+        "DET",
+        "Detroit",
+        "Pistons",
+        Division::Central,
+        0xC8102E,
+        0x1D42BA,
+    ),
+    Team::new(
+        "1610612766",
+        "CHA",
+        "Charlotte",
+        "Hornets",
+        Division::Southeast,
+        0x1D1160,
+        0x00788C,
+    ),
+];
+
+/// Looks up a team by id, `None` for non NBA teams such as all-star or
+/// international preseason teams.
+pub fn from_id(team_id: &str) -> Option<&'static Team> {
+    TEAMS.iter().find(|x| x.team_id == team_id)
+}
+
+/// Looks up a team by tri code, ignoring case.
+pub fn from_tri_code(tri_code: &str) -> Option<&'static Team> {
+    TEAMS
+        .iter()
+        .find(|x| x.tri_code.eq_ignore_ascii_case(tri_code))
+}
@@ -0,0 +1,299 @@
+use crate::config::Config;
+use nil::{teams, Error};
+use serde_derive::{Deserialize, Serialize};
+use std::env;
+use tui::style::{Color, Modifier, Style};
+
+/// Colors a terminal can show.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorDepth {
+    Ansi16,
+    Ansi256,
+    TrueColor,
+}
+
+impl ColorDepth {
+    /// Guesses the depth from the `COLORTERM` and `TERM` variables.
+    pub fn detect() -> Self {
+        let colorterm = env::var("COLORTERM").unwrap_or_default();
+        let term = env::var("TERM").unwrap_or_default();
+        if colorterm == "truecolor" || colorterm == "24bit" {
+            ColorDepth::TrueColor
+        } else if term.contains("256color") {
+            ColorDepth::Ansi256
+        } else {
+            ColorDepth::Ansi16
+        }
+    }
+
+    /// Approximates `color` with the colors of the terminal.
+    pub fn downgrade(self, color: Color) -> Color {
+        match (self, color) {
+            (ColorDepth::TrueColor, _) => color,
+            (ColorDepth::Ansi256, Color::Rgb(r, g, b)) => Color::Indexed(ansi_256(r, g, b)),
+            (ColorDepth::Ansi16, Color::Rgb(r, g, b)) => ansi_16(r, g, b),
+            (ColorDepth::Ansi16, Color::Indexed(i)) if i >= 16 => {
+                let (r, g, b) = indexed_rgb(i);
+                ansi_16(r, g, b)
+            }
+            _ => color,
+        }
+    }
+}
+
+/// Nearest color of the 6x6x6 cube of the 256 color palette.
+fn ansi_256(r: u8, g: u8, b: u8) -> u8 {
+    let level = |x: u8| {
+        if x < 48 {
+            0
+        } else if x < 115 {
+            1
+        } else {
+            (x - 35) / 40
+        }
+    };
+    16 + 36 * level(r) + 6 * level(g) + level(b)
+}
+
+fn indexed_rgb(i: u8) -> (u8, u8, u8) {
+    if i >= 232 {
+        let x = 8 + (i - 232) * 10;
+        return (x, x, x);
+    }
+    let i = i - 16;
+    let level = |x: u8| if x == 0 { 0 } else { 55 + x * 40 };
+    (level(i / 36), level(i / 6 % 6), level(i % 6))
+}
+
+const ANSI_16: [(Color, (u8, u8, u8)); 16] = [
+    (Color::Black, (0, 0, 0)),
+    (Color::Red, (205, 0, 0)),
+    (Color::Green, (0, 205, 0)),
+    (Color::Yellow, (205, 205, 0)),
+    (Color::Blue, (0, 0, 238)),
+    (Color::Magenta, (205, 0, 205)),
+    (Color::Cyan, (0, 205, 205)),
+    (Color::Gray, (229, 229, 229)),
+    (Color::DarkGray, (127, 127, 127)),
+    (Color::LightRed, (255, 0, 0)),
+    (Color::LightGreen, (0, 255, 0)),
+    (Color::LightYellow, (255, 255, 0)),
+    (Color::LightBlue, (92, 92, 255)),
+    (Color::LightMagenta, (255, 0, 255)),
+    (Color::LightCyan, (0, 255, 255)),
+    (Color::White, (255, 255, 255)),
+];
+
+/// Nearest of the 16 standard colors, using the xterm palette.
+fn ansi_16(r: u8, g: u8, b: u8) -> Color {
+    let distance = |(x, y, z): (u8, u8, u8)| {
+        let d = |a: u8, b: u8| (a as i32 - b as i32).pow(2);
+        d(r, x) + d(g, y) + d(b, z)
+    };
+    ANSI_16
+        .iter()
+        .min_by_key(|(_, rgb)| distance(*rgb))
+        .map(|(color, _)| *color)
+        .unwrap()
+}
+
+/// Parses `red`, `lightblue`, `#1d428a` or a palette index like `208`.
+fn parse_color(s: &str) -> Result<Color, Error> {
+    let color = match s.to_ascii_lowercase().as_str() {
+        "reset" => Color::Reset,
+        "black" => Color::Black,
+        "red" => Color::Red,
+        "green" => Color::Green,
+        "yellow" => Color::Yellow,
+        "blue" => Color::Blue,
+        "magenta" => Color::Magenta,
+        "cyan" => Color::Cyan,
+        "gray" => Color::Gray,
+        "darkgray" => Color::DarkGray,
+        "lightred" => Color::LightRed,
+        "lightgreen" => Color::LightGreen,
+        "lightyellow" => Color::LightYellow,
+        "lightblue" => Color::LightBlue,
+        "lightmagenta" => Color::LightMagenta,
+        "lightcyan" => Color::LightCyan,
+        "white" => Color::White,
+        hex if hex.starts_with('#') && hex.len() == 7 => {
+            let rgb =
+                u32::from_str_radix(&hex[1..], 16).map_err(|_| format!("invalid color `{}`", s))?;
+            rgb_color(rgb)
+        }
+        index => Color::Indexed(
+            index
+                .parse()
+                .map_err(|_| format!("invalid color `{}`", s))?,
+        ),
+    };
+    Ok(color)
+}
+
+fn rgb_color(rgb: u32) -> Color {
+    Color::Rgb((rgb >> 16) as u8, (rgb >> 8) as u8, rgb as u8)
+}
+
+/// Colors of a user defined theme in the config file, the missing ones
+/// taken from `base`.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ThemeColors {
+    pub base: Option<String>,
+    pub tab: Option<String>,
+    pub highlight: Option<String>,
+    pub positive: Option<String>,
+    pub negative: Option<String>,
+    pub muted: Option<String>,
+    pub accent: Option<String>,
+    pub favorite: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Theme {
+    /// Tab titles and team names.
+    pub tab: Color,
+    /// Selected items and table headers.
+    pub highlight: Color,
+    /// Wins, players on court and finished requests.
+    pub positive: Color,
+    /// Losses, players on the bench and errors.
+    pub negative: Color,
+    pub muted: Color,
+    pub accent: Color,
+    pub favorite: Color,
+    /// Whether teams use their official colors.
+    pub team_colors: bool,
+    pub depth: ColorDepth,
+}
+
+impl Theme {
+    pub const BUILT_IN: [&'static str; 3] = ["dark", "light", "high-contrast"];
+
+    fn built_in(name: &str) -> Option<Self> {
+        let (tab, highlight, positive, negative, muted, accent, favorite) = match name {
+            "dark" => (
+                Color::Green,
+                Color::Yellow,
+                Color::Green,
+                Color::Red,
+                Color::DarkGray,
+                Color::Magenta,
+                Color::Cyan,
+            ),
+            "light" => (
+                Color::Blue,
+                Color::Magenta,
+                Color::Green,
+                Color::Red,
+                Color::DarkGray,
+                Color::Magenta,
+                Color::Blue,
+            ),
+            "high-contrast" => (
+                Color::White,
+                Color::LightYellow,
+                Color::LightGreen,
+                Color::LightRed,
+                Color::Gray,
+                Color::LightMagenta,
+                Color::LightCyan,
+            ),
+            _ => return None,
+        };
+        Some(Theme {
+            tab,
+            highlight,
+            positive,
+            negative,
+            muted,
+            accent,
+            favorite,
+            team_colors: false,
+            depth: ColorDepth::TrueColor,
+        })
+    }
+
+    /// Looks up `config.theme` in the built-in themes, then in the themes of
+    /// the config file.
+    pub fn new(config: &Config) -> Result<Self, Error> {
+        let mut theme = Theme::named(config, &config.theme, 0)?;
+        theme.team_colors = config.team_colors;
+        theme.depth = ColorDepth::detect();
+        for color in [
+            &mut theme.tab,
+            &mut theme.highlight,
+            &mut theme.positive,
+            &mut theme.negative,
+            &mut theme.muted,
+            &mut theme.accent,
+            &mut theme.favorite,
+        ]
+        .iter_mut()
+        {
+            **color = theme.depth.downgrade(**color);
+        }
+        Ok(theme)
+    }
+
+    fn named(config: &Config, name: &str, depth: usize) -> Result<Self, Error> {
+        if let Some(theme) = Theme::built_in(name) {
+            return Ok(theme);
+        }
+        let colors = match config.themes.get(name) {
+            Some(colors) if depth < 8 => colors,
+            Some(_) => return Err(format!("theme `{}` inherits from itself", name).into()),
+            None => {
+                return Err(format!(
+                    "unknown theme `{}`, expected one of {} or a theme of the config file",
+                    name,
+                    Theme::BUILT_IN.join(", ")
+                )
+                .into())
+            }
+        };
+        let mut theme = Theme::named(config, colors.base.as_deref().unwrap_or("dark"), depth + 1)?;
+        for (color, value) in [
+            (&mut theme.tab, &colors.tab),
+            (&mut theme.highlight, &colors.highlight),
+            (&mut theme.positive, &colors.positive),
+            (&mut theme.negative, &colors.negative),
+            (&mut theme.muted, &colors.muted),
+            (&mut theme.accent, &colors.accent),
+            (&mut theme.favorite, &colors.favorite),
+        ]
+        .iter_mut()
+        {
+            if let Some(value) = value {
+                **color = parse_color(value)?;
+            }
+        }
+        Ok(theme)
+    }
+
+    /// Style of the name of the team `team_id`, in its official colors when
+    /// enabled, underlined for favorites.
+    pub fn team(&self, team_id: &str, favorite: bool) -> Style {
+        let style = match teams::from_id(team_id) {
+            Some(team) if self.team_colors => Style::default()
+                .fg(self.depth.downgrade(rgb_color(team.secondary)))
+                .bg(self.depth.downgrade(rgb_color(team.primary))),
+            _ if favorite => Style::default().fg(self.favorite),
+            _ => Style::default().fg(self.tab),
+        };
+        if favorite {
+            style.add_modifier(Modifier::BOLD | Modifier::UNDERLINED)
+        } else {
+            style
+        }
+    }
+
+    /// A single color for the team `team_id`, like a chart series.
+    pub fn team_color(&self, team_id: &str) -> Color {
+        match teams::from_id(team_id) {
+            Some(team) if self.team_colors => self.depth.downgrade(rgb_color(team.primary)),
+            _ => self.tab,
+        }
+    }
+}
@@ -1,36 +1,274 @@
-use crate::app::App;
+use crate::app::{scroll_offset, App, Status, Tab, TabTeam};
+use crate::columns::{self, Column};
+use crate::keys::Action;
+use crate::network::Resource;
+use crate::theme::Theme;
+use nil::boxscore::GameStats;
+use nil::bracket::Series;
+use nil::pbp::{self, Shot, Zone, CORNER_THREE_X, CORNER_THREE_Y};
+use nil::schedule::Game;
+use nil::standings::{format_games_behind, format_pct, Standing};
+use nil::stats::{FourFactors, Stats};
+use nil::teams::{self, Conference, Division, TEAMS};
 use tui::{
     backend::Backend,
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
+    symbols::Marker,
     text::{Span, Spans},
-    widgets::{Block, Borders, List, ListItem, Paragraph, Row, Table, Tabs, Wrap},
+    widgets::{
+        canvas::{Canvas, Context, Line, Points, Rectangle},
+        Axis, Block, Borders, Chart, Clear, Dataset, Gauge, List, ListItem, ListState, Paragraph,
+        Row, Table, TableState, Tabs, Wrap,
+    },
     Frame,
 };
 
 pub fn draw<B: Backend>(f: &mut Frame<B>, app: &mut App) {
+    if app.schedule.is_none() {
+        return draw_loading(f, app, f.size());
+    }
     let chunks = Layout::default()
         .constraints([Constraint::Length(3), Constraint::Min(0)].as_ref())
         .split(f.size());
-    let titles = app
-        .tabs
-        .titles
+    let titles = Tab::ALL
         .iter()
-        .map(|t| Spans::from(Span::styled(*t, Style::default().fg(Color::Green))))
+        .map(|t| Spans::from(Span::styled(t.title(), Style::default().fg(app.theme.tab))))
         .collect();
+    let mut title = vec![Span::raw(app.title)];
+    if let Some(updated) = app.last_update {
+        title.push(Span::raw(format!(
+            " | Updated {}s ago",
+            updated.elapsed().as_secs()
+        )));
+    }
+    if let Some(key) = app.keys.keys(Action::Help).first() {
+        title.push(Span::raw(format!(" | {} for help", key)));
+    }
+    if let Some(e) = app.stale_error() {
+        title.push(Span::styled(
+            format!(" | Refresh failed, data is stale: {}", e),
+            Style::default()
+                .fg(app.theme.negative)
+                .add_modifier(Modifier::BOLD),
+        ));
+    }
     let tabs = Tabs::new(titles)
-        .block(Block::default().borders(Borders::ALL).title(app.title))
-        .highlight_style(Style::default().fg(Color::Yellow))
-        .select(app.tabs.index);
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(Spans::from(title)),
+        )
+        .highlight_style(Style::default().fg(app.theme.highlight))
+        .select(app.tabs.index());
     f.render_widget(tabs, chunks[0]);
-    match app.tabs.index {
-        0 => draw_first_tab(f, app, chunks[1]),
-        1 => draw_second_tab(f, app, chunks[1]),
-        _ => unreachable!(),
+    let titles: Vec<&str> = Tab::ALL.iter().map(|x| x.title()).collect();
+    app.areas.tabs = tab_areas(chunks[0], &titles);
+    let tab = app.tabs.tab;
+    match tab {
+        // The game tabs need the boxscore, the others only the schedule
+        _ if tab.is_game() && app.no_games() => draw_empty_games(f, chunks[1]),
+        _ if tab.is_game() && app.game_error().is_some() => draw_game_error(f, app, chunks[1]),
+        _ if tab.is_game() && app.boxscore.is_none() => draw_loading(f, app, chunks[1]),
+        Tab::Game => draw_first_tab(f, app, chunks[1]),
+        Tab::Boxscore => draw_second_tab(f, app, chunks[1]),
+        Tab::Scoreboard => draw_scoreboard(f, app, chunks[1]),
+        Tab::Standings => draw_standings(f, app, chunks[1]),
+        Tab::Bracket => draw_bracket(f, app, chunks[1]),
+        Tab::Team => draw_team_schedule(f, app, chunks[1]),
+        Tab::Compare => draw_compare(f, app, chunks[1]),
+        Tab::Lineups => draw_lineups(f, app, chunks[1]),
+        Tab::Rotation => draw_rotation(f, app, chunks[1]),
+        Tab::Shots => draw_shots(f, app, chunks[1]),
     };
+    if app.show_help {
+        draw_help(f, app);
+    } else if app.show_player && app.tabs.tab == Tab::Boxscore {
+        draw_player(f, app);
+    }
+}
+
+/// Area of `width` by `height` centered in `area`.
+fn popup_area(area: Rect, width: u16, height: u16) -> Rect {
+    let (width, height) = (width.min(area.width), height.min(area.height));
+    Rect::new(
+        area.x + (area.width - width) / 2,
+        area.y + (area.height - height) / 2,
+        width,
+        height,
+    )
+}
+
+/// Popup listing the actions of the current tab and their keys.
+fn draw_help<B: Backend>(f: &mut Frame<B>, app: &App) {
+    let rows: Vec<Row> = Action::ALL
+        .iter()
+        .filter(|x| x.is_available(app.tabs.tab))
+        .filter_map(|&action| {
+            let keys = app.keys.keys(action);
+            if keys.is_empty() {
+                return None;
+            }
+            let keys: Vec<String> = keys.iter().map(|x| x.to_string()).collect();
+            Some(Row::new([
+                keys.join(", "),
+                action.description().to_string(),
+            ]))
+        })
+        .collect();
+    let area = popup_area(f.size(), 60, rows.len() as u16 + 4);
+    let table = Table::new(rows)
+        .header(
+            Row::new(["Keys", "Action"])
+                .style(Style::default().fg(app.theme.highlight))
+                .bottom_margin(1),
+        )
+        .block(
+            Block::default()
+                .title(format!("Help - {}", app.tabs.tab.title()))
+                .borders(Borders::ALL),
+        )
+        .widths(&[Constraint::Length(24), Constraint::Min(0)]);
+    f.render_widget(Clear, area);
+    f.render_widget(table, area);
 }
 
-pub fn draw_empty_games<B: Backend>(f: &mut Frame<B>) {
+/// Popup with the game line, shooting splits and season averages of the
+/// selected player of the boxscore.
+fn draw_player<B: Backend>(f: &mut Frame<B>, app: &App) {
+    let player = match app.selected_player() {
+        Some(x) => x,
+        None => return,
+    };
+    let stat = |x: Option<&'static str>| x.filter(|x| !x.is_empty()).unwrap_or("0");
+    let label = |x: &'static str| Span::styled(x, Style::default().fg(app.theme.highlight));
+    let split = |name, made, attempted, pct| {
+        Spans::from(vec![
+            label(name),
+            Span::raw(format!(
+                "{}-{} ({}%)",
+                stat(made),
+                stat(attempted),
+                stat(pct)
+            )),
+        ])
+    };
+
+    let (status, color) = match player.is_on_court {
+        Some(true) => (
+            format!("{} On court", glyphs(app).on_court),
+            app.theme.positive,
+        ),
+        Some(false) => ("Bench".to_string(), app.theme.negative),
+        None => (String::new(), app.theme.muted),
+    };
+    let mut text = vec![
+        Spans::from(vec![
+            Span::raw(format!(
+                "#{} {}",
+                player.jersey().unwrap_or("-"),
+                player
+                    .position_full()
+                    .filter(|x| !x.is_empty())
+                    .unwrap_or("-")
+            )),
+            Span::raw("  "),
+            Span::styled(status, Style::default().fg(color)),
+        ]),
+        Spans::default(),
+    ];
+    match player.dnp.filter(|x| !x.is_empty()) {
+        Some(dnp) => text.push(Spans::from(format!("DNP - {}", dnp))),
+        None => {
+            text.push(Spans::from(vec![
+                label("MIN "),
+                Span::raw(format!("{:<6}", stat(player.min))),
+                label("PTS "),
+                Span::raw(format!("{:<4}", stat(player.points))),
+                label("+/- "),
+                Span::raw(stat(player.plus_minus)),
+            ]));
+            text.push(Spans::from(vec![
+                label("REB "),
+                Span::raw(format!(
+                    "{} ({} off, {} def)",
+                    stat(player.tot_reb),
+                    stat(player.off_reb),
+                    stat(player.def_reb)
+                )),
+            ]));
+            text.push(Spans::from(vec![
+                label("AST "),
+                Span::raw(format!("{:<4}", stat(player.assists))),
+                label("STL "),
+                Span::raw(format!("{:<4}", stat(player.steals))),
+                label("BLK "),
+                Span::raw(format!("{:<4}", stat(player.blocks))),
+                label("BLKA "),
+                Span::raw(stat(player.blocks_against)),
+            ]));
+            text.push(Spans::from(vec![
+                label("TOV "),
+                Span::raw(format!("{:<4}", stat(player.turnovers))),
+                label("PF "),
+                Span::raw(stat(player.p_fouls)),
+            ]));
+            text.push(Spans::default());
+            text.push(split("FG  ", player.fgm, player.fga, player.fgp));
+            text.push(split("3P  ", player.tpm, player.tpa, player.tpp));
+            text.push(split("FT  ", player.ftm, player.fta, player.ftp));
+        }
+    }
+
+    text.push(Spans::default());
+    let person_id = player.person_id();
+    match app.profiles.get(person_id) {
+        Some(x) => {
+            text.push(Spans::from(Span::styled(
+                format!(
+                    "{}-{:02} season, {} games",
+                    x.season_year,
+                    (x.season_year + 1) % 100,
+                    x.games_played
+                ),
+                Style::default().fg(app.theme.highlight),
+            )));
+            text.push(Spans::from(format!(
+                "{:.1} min  {:.1} pts  {:.1} reb  {:.1} ast",
+                x.mpg, x.ppg, x.rpg, x.apg
+            )));
+            text.push(Spans::from(format!(
+                "{:.1} stl  {:.1} blk  {:.1} tov",
+                x.spg, x.bpg, x.topg
+            )));
+            text.push(Spans::from(format!(
+                "{:.1} FG%  {:.1} 3P%  {:.1} FT%",
+                x.fgp, x.tpp, x.ftp
+            )));
+        }
+        None if app.profile_failures.contains_key(person_id) => {
+            text.push(Spans::from(Span::styled(
+                "Season averages unavailable",
+                Style::default().fg(app.theme.muted),
+            )))
+        }
+        None => text.push(Spans::from(Span::styled(
+            "Loading season averages...",
+            Style::default().fg(app.theme.muted),
+        ))),
+    }
+
+    let area = popup_area(f.size(), 50, text.len() as u16 + 2);
+    let para = Paragraph::new(text).block(
+        Block::default()
+            .title(format!("{} {}", player.first_name, player.last_name))
+            .borders(Borders::ALL),
+    );
+    f.render_widget(Clear, area);
+    f.render_widget(para, area);
+}
+
+pub fn draw_empty_games<B: Backend>(f: &mut Frame<B>, area: Rect) {
     let chunks = Layout::default()
         .constraints(
             [
@@ -40,30 +278,163 @@ pub fn draw_empty_games<B: Backend>(f: &mut Frame<B>) {
             ]
             .as_ref(),
         )
-        .split(f.size());
+        .split(area);
     let text = Span::raw("There are no games today.");
     let para = Paragraph::new(text).alignment(Alignment::Center);
 
     f.render_widget(para, chunks[1]);
 }
 
-fn draw_first_tab<B>(f: &mut Frame<B>, app: &mut App, area: Rect)
-where
-    B: Backend,
-{
+/// Error of the failed fetch of the current game, in place of a game tab.
+fn draw_game_error<B: Backend>(f: &mut Frame<B>, app: &App, area: Rect) {
+    let error = app.game_error().unwrap_or_default();
+    let mut text = vec![
+        Spans::from(Span::styled(
+            error.to_string(),
+            Style::default().fg(app.theme.negative),
+        )),
+        Spans::from(""),
+    ];
+    let retry = match app.keys.keys(Action::Refresh).first() {
+        Some(key) => format!("Press {} to retry, or wait for the next refresh.", key),
+        None => "Retrying on the next refresh.".to_string(),
+    };
+    text.push(Spans::from(Span::styled(
+        retry,
+        Style::default().fg(app.theme.muted),
+    )));
+    let area = popup_area(area, 60, 8);
+    let para = Paragraph::new(text)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Could not load the game"),
+        )
+        .wrap(Wrap { trim: true });
+
+    f.render_widget(para, area);
+}
+
+fn draw_loading<B: Backend>(f: &mut Frame<B>, app: &App, area: Rect) {
+    let height = Resource::ALL.len() as u16 + 2;
     let chunks = Layout::default()
         .constraints(
             [
-                Constraint::Length(9),
-                Constraint::Min(8),
-                Constraint::Length(7),
+                Constraint::Min(0),
+                Constraint::Length(height),
+                Constraint::Min(0),
             ]
             .as_ref(),
         )
         .split(area);
+    let area = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(
+            [
+                Constraint::Percentage(25),
+                Constraint::Percentage(50),
+                Constraint::Percentage(25),
+            ]
+            .as_ref(),
+        )
+        .split(chunks[1])[1];
+    let (done, failed) = if app.enhanced_graphics {
+        ("✓", "✗")
+    } else {
+        ("+", "x")
+    };
+    let text: Vec<Spans> = Resource::ALL
+        .iter()
+        .map(|&r| {
+            let (symbol, status, style) = match app.status(r) {
+                Status::Idle => (" ", "-".to_string(), Style::default().fg(app.theme.muted)),
+                Status::Loading => (
+                    app.spinner(),
+                    "loading".to_string(),
+                    Style::default().fg(app.theme.highlight),
+                ),
+                Status::Loaded => (
+                    done,
+                    "done".to_string(),
+                    Style::default().fg(app.theme.positive),
+                ),
+                Status::Failed(e) => (
+                    failed,
+                    format!("failed: {}", e),
+                    Style::default().fg(app.theme.negative),
+                ),
+            };
+            Spans::from(vec![
+                Span::styled(format!(" {} ", symbol), style),
+                Span::raw(format!("{: <14}", r.name())),
+                Span::styled(status, style),
+            ])
+        })
+        .collect();
+    let para = Paragraph::new(text)
+        .block(Block::default().borders(Borders::ALL).title("Loading"))
+        .wrap(Wrap { trim: true });
+
+    f.render_widget(para, area);
+}
+
+fn draw_first_tab<B>(f: &mut Frame<B>, app: &mut App, area: Rect)
+where
+    B: Backend,
+{
+    let chunks = Layout::default()
+        .constraints([Constraint::Length(9), Constraint::Min(8)].as_ref())
+        .split(area);
     draw_gauges(f, app, chunks[0]);
     draw_charts(f, app, chunks[1]);
-    draw_text(f, chunks[2]);
+}
+
+/// Symbols of the ui, Nerd Font glyphs with enhanced graphics or plain ASCII.
+struct Glyphs {
+    scoring: &'static str,
+    run: &'static str,
+    possession: &'static str,
+    on_court: &'static str,
+    bench: &'static str,
+    bar: &'static str,
+    separator: &'static str,
+    made: &'static str,
+    missed: &'static str,
+    marker: Marker,
+}
+
+const NERD_GLYPHS: Glyphs = Glyphs {
+    scoring: "\u{f0806}",
+    run: "\u{2191}",
+    possession: "\u{25c0}",
+    on_court: "\u{25cf}",
+    bench: " ",
+    bar: "\u{2588}",
+    separator: "\u{2502}",
+    made: "\u{25cf}",
+    missed: "\u{00d7}",
+    marker: Marker::Braille,
+};
+
+const ASCII_GLYPHS: Glyphs = Glyphs {
+    scoring: "+",
+    run: "^",
+    possession: "<",
+    on_court: "*",
+    bench: " ",
+    bar: "#",
+    separator: "|",
+    made: "o",
+    missed: "x",
+    marker: Marker::Dot,
+};
+
+fn glyphs(app: &App) -> &'static Glyphs {
+    if app.enhanced_graphics {
+        &NERD_GLYPHS
+    } else {
+        &ASCII_GLYPHS
+    }
 }
 
 fn draw_gauges<B>(f: &mut Frame<B>, app: &mut App, area: Rect)
@@ -71,18 +442,122 @@ where
     B: Backend,
 {
     let chunks = Layout::default()
-        .constraints(
-            [
-                Constraint::Length(2),
-                Constraint::Length(3),
-                Constraint::Length(1),
-            ]
-            .as_ref(),
-        )
-        .margin(1)
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Length(24), Constraint::Min(0)].as_ref())
         .split(area);
-    let block = Block::default().borders(Borders::ALL).title("Graphs");
-    f.render_widget(block, area);
+    draw_score(f, app, chunks[0]);
+    draw_score_flow(f, app, chunks[1]);
+}
+
+/// Score with the possession indicator and the current run.
+fn draw_score<B>(f: &mut Frame<B>, app: &App, area: Rect)
+where
+    B: Backend,
+{
+    let boxscore = match &app.boxscore {
+        Some(b) => b,
+        None => return,
+    };
+    let glyphs = glyphs(app);
+    let possession = app.possession();
+    let line = |team: &nil::Team| {
+        let indicator = if possession == Some(team.tri_code) {
+            glyphs.possession
+        } else {
+            " "
+        };
+        Spans::from(vec![
+            Span::styled(
+                format!("{: <4}", team.tri_code),
+                app.theme.team(team.team_id, app.is_favorite(team.team_id)),
+            ),
+            Span::raw(format!("{: >4} {}", team.score(), indicator)),
+        ])
+    };
+    let mut text = vec![line(&boxscore.v_team), line(&boxscore.h_team)];
+    if let Some((home, points)) = app.current_run() {
+        let team = if home {
+            &boxscore.h_team
+        } else {
+            &boxscore.v_team
+        };
+        text.push(Spans::from(""));
+        text.push(Spans::from(Span::styled(
+            format!("{} {} {}-0 run", glyphs.run, team.tri_code, points),
+            Style::default().fg(app.theme.accent),
+        )));
+    }
+    let paragraph =
+        Paragraph::new(text).block(Block::default().borders(Borders::ALL).title("Score"));
+    f.render_widget(paragraph, area);
+}
+
+/// Home team margin over the game, above zero when the home team leads.
+fn draw_score_flow<B>(f: &mut Frame<B>, app: &App, area: Rect)
+where
+    B: Backend,
+{
+    let boxscore = match &app.boxscore {
+        Some(b) => b,
+        None => return,
+    };
+    let mut home = Vec::new();
+    let mut visitor = Vec::new();
+    let plays = &app.plays.items;
+    for (play, minutes) in plays.iter().zip(pbp::elapsed(plays)) {
+        let point = (minutes as f64, play.h_score as f64 - play.v_score as f64);
+        if point.1 >= 0.0 {
+            home.push(point);
+        } else {
+            visitor.push(point);
+        }
+    }
+    let max_margin = home
+        .iter()
+        .chain(visitor.iter())
+        .map(|x| x.1.abs())
+        .fold(5.0, f64::max);
+    let end = pbp::period_start(pbp::periods(plays) + 1) as f64;
+    let marker = glyphs(app).marker;
+    let (h_color, v_color) = if app.theme.team_colors {
+        (
+            app.theme.team_color(boxscore.h_team.team_id),
+            app.theme.team_color(boxscore.v_team.team_id),
+        )
+    } else {
+        (app.theme.tab, app.theme.accent)
+    };
+    let datasets = vec![
+        Dataset::default()
+            .name(boxscore.h_team.tri_code)
+            .marker(marker)
+            .style(Style::default().fg(h_color))
+            .data(&home),
+        Dataset::default()
+            .name(boxscore.v_team.tri_code)
+            .marker(marker)
+            .style(Style::default().fg(v_color))
+            .data(&visitor),
+    ];
+    let muted = Style::default().fg(app.theme.muted);
+    let chart = Chart::new(datasets)
+        .block(Block::default().borders(Borders::ALL).title("Margin"))
+        .x_axis(Axis::default().style(muted).bounds([0.0, end]).labels(vec![
+            Span::raw("Start"),
+            Span::raw("Half"),
+            Span::raw("End"),
+        ]))
+        .y_axis(
+            Axis::default()
+                .style(muted)
+                .bounds([-max_margin, max_margin])
+                .labels(vec![
+                    Span::raw(format!("{} +{:.0}", boxscore.v_team.tri_code, max_margin)),
+                    Span::raw("0"),
+                    Span::raw(format!("{} +{:.0}", boxscore.h_team.tri_code, max_margin)),
+                ]),
+        );
+    f.render_widget(chart, area);
 }
 
 fn draw_charts<B>(f: &mut Frame<B>, app: &mut App, area: Rect)
@@ -99,64 +574,84 @@ where
             .constraints([Constraint::Percentage(100)].as_ref())
             .split(chunks[0]);
 
+        let glyphs = glyphs(app);
+        let tri_code = |home| match &app.boxscore {
+            Some(b) if home => b.h_team.tri_code,
+            Some(b) => b.v_team.tri_code,
+            None => "",
+        };
+        let run_style = Style::default().fg(app.theme.accent);
         let plays: Vec<ListItem> = app
             .plays
             .items
             .iter()
-            .map(|i| {
-                ListItem::new(vec![Spans::from(format!(
-                    "{} {} | {} - {} | {}",
-                    &i.period, i.clock, i.h_score, i.v_score, i.description
-                ))])
+            .enumerate()
+            .map(|(n, i)| {
+                let previous = n.checked_sub(1).and_then(|x| app.plays.items.get(x));
+                let marker = if i.is_scoring(previous) {
+                    glyphs.scoring
+                } else {
+                    " "
+                };
+                let run = app.flow.runs.iter().find(|x| x.start <= n && n <= x.end);
+                let mut spans = vec![
+                    Span::styled(run.map_or(" ", |_| glyphs.run), run_style),
+                    Span::raw(format!(
+                        "{} {} {} | {} - {} | {}",
+                        marker,
+                        pbp::period_name(i.period),
+                        i.clock,
+                        i.h_score,
+                        i.v_score,
+                        i.description
+                    )),
+                ];
+                if let Some(run) = run.filter(|x| x.end == n) {
+                    spans.push(Span::styled(
+                        format!(" ({} {}-0 run)", tri_code(run.home), run.points),
+                        run_style,
+                    ));
+                }
+                ListItem::new(vec![Spans::from(spans)])
             })
             .collect();
+        let title = match app.current_game() {
+            Some(game) if app.plays.items.is_empty() && game.status_num() == 1 => {
+                format!("Play by play - Starts at {}", app.start_time(game))
+            }
+            _ => "Play by play".to_string(),
+        };
         let plays = List::new(plays)
-            .block(Block::default().borders(Borders::ALL).title("Play by play"))
+            .block(Block::default().borders(Borders::ALL).title(title))
             .highlight_style(Style::default().add_modifier(Modifier::BOLD))
             .highlight_symbol("> ");
+        let inner = Block::default().borders(Borders::ALL).inner(chunks[0]);
+        app.plays.scroll(inner.height as usize);
+        app.areas.plays = inner;
         f.render_stateful_widget(plays, chunks[0], &mut app.plays.state);
     }
 }
 
-fn draw_text<B>(f: &mut Frame<B>, area: Rect)
+/// Tabs of the home and visitor teams of the boxscore.
+fn draw_team_tabs<B>(f: &mut Frame<B>, app: &mut App, area: Rect)
 where
     B: Backend,
 {
-    let text = vec![
-        Spans::from("This is a paragraph with several lines. You can change style your text the way you want"),
-        Spans::from(""),
-        Spans::from(vec![
-            Span::from("For example: "),
-            Span::styled("under", Style::default().fg(Color::Red)),
-            Span::raw(" "),
-            Span::styled("the", Style::default().fg(Color::Green)),
-            Span::raw(" "),
-            Span::styled("rainbow", Style::default().fg(Color::Blue)),
-            Span::raw("."),
-        ]),
-        Spans::from(vec![
-            Span::raw("Oh and if you didn't "),
-            Span::styled("notice", Style::default().add_modifier(Modifier::ITALIC)),
-            Span::raw(" you can "),
-            Span::styled("automatically", Style::default().add_modifier(Modifier::BOLD)),
-            Span::raw(" "),
-            Span::styled("wrap", Style::default().add_modifier(Modifier::REVERSED)),
-            Span::raw(" your "),
-            Span::styled("text", Style::default().add_modifier(Modifier::UNDERLINED)),
-            Span::raw(".")
-        ]),
-        Spans::from(
-            "One more thing is that it should display unicode characters: 10€"
-        ),
-    ];
-    let block = Block::default().borders(Borders::ALL).title(Span::styled(
-        "Footer",
-        Style::default()
-            .fg(Color::Magenta)
-            .add_modifier(Modifier::BOLD),
-    ));
-    let paragraph = Paragraph::new(text).block(block).wrap(Wrap { trim: true });
-    f.render_widget(paragraph, area);
+    let boxscore = match &app.boxscore {
+        Some(b) => b,
+        None => return,
+    };
+    let team_title = |team: &nil::Team| {
+        let style = app.theme.team(team.team_id, app.is_favorite(team.team_id));
+        Spans::from(Span::styled(team.tri_code.to_string(), style))
+    };
+    let titles = vec![team_title(&boxscore.h_team), team_title(&boxscore.v_team)];
+    let tabs = Tabs::new(titles)
+        .block(Block::default().borders(Borders::ALL).title("Team"))
+        .highlight_style(Style::default().fg(app.theme.highlight))
+        .select(app.tabs.team as usize);
+    f.render_widget(tabs, area);
+    app.areas.team_tabs = tab_areas(area, &[boxscore.h_team.tri_code, boxscore.v_team.tri_code]);
 }
 
 fn draw_second_tab<B>(f: &mut Frame<B>, app: &mut App, area: Rect)
@@ -166,79 +661,413 @@ where
     let chunks = Layout::default()
         .constraints([Constraint::Length(3), Constraint::Min(0)].as_ref())
         .split(area);
-    let titles = vec![
-        Spans::from(Span::styled(
-            app.boxscore.h_team.tri_code,
-            Style::default().fg(Color::Green),
-        )),
-        Spans::from(Span::styled(
-            app.boxscore.v_team.tri_code,
-            Style::default().fg(Color::Green),
-        )),
-    ];
-    let tabs = Tabs::new(titles)
-        .block(Block::default().borders(Borders::ALL).title("Team"))
-        .highlight_style(Style::default().fg(Color::Yellow))
-        .select(app.tabs.team as usize);
-    f.render_widget(tabs, chunks[0]);
-    let up_style = Style::default().fg(Color::Green);
-    let down_style = Style::default().fg(Color::Red);
-    let rows = app.boxscore.players.iter().filter_map(|s| {
-        if s.team_id.unwrap() == app.get_current_team() {
-            let style = if let Some(true) = s.is_on_court {
-                up_style
+    draw_team_tabs(f, app, chunks[0]);
+    let inner = Block::default().borders(Borders::ALL).inner(chunks[1]);
+    if inner.width < CARD_MODE_WIDTH {
+        return draw_player_cards(f, app, chunks[1]);
+    }
+    let up_style = Style::default().fg(app.theme.positive);
+    let down_style = Style::default().fg(app.theme.negative);
+    let glyphs = glyphs(app);
+    let (columns, hidden) = box_columns(&app.box_columns, inner.width - NAME_WIDTH - 1);
+    // Scroll stat columns with the player name frozen
+    let fits = |columns: &[&Column]| {
+        let mut width = NAME_WIDTH;
+        columns
+            .iter()
+            .take_while(|x| {
+                width += x.width + 1;
+                width <= inner.width
+            })
+            .count()
+    };
+    let reversed: Vec<&Column> = columns.iter().rev().copied().collect();
+    app.box_column = app.box_column.min(columns.len() - fits(&reversed));
+    let scrollable = fits(&columns) < columns.len();
+    let columns = &columns[app.box_column..];
+    let columns = &columns[..fits(columns)];
+
+    let players = app.team_players();
+    let totals = app.team_totals();
+    let rows = players.iter().map(|s| {
+        let (on_court, style) = if let Some(true) = s.is_on_court {
+            (glyphs.on_court, up_style)
+        } else {
+            (glyphs.bench, down_style)
+        };
+        let mut cells = vec![format!("{}{} {}", on_court, s.first_name, s.last_name)];
+        let stats = app.player_stats(s);
+        cells.extend(
+            columns
+                .iter()
+                .map(|x| format!("{: >1$}", (x.value)(s, &stats, &totals), x.width as usize)),
+        );
+        Row::new(cells).style(style)
+    });
+    let mut header = vec!["Player".to_string()];
+    header.extend(
+        columns
+            .iter()
+            .map(|x| format!("{: >1$}", x.header, x.width as usize)),
+    );
+    let mut widths = vec![Constraint::Length(NAME_WIDTH)];
+    widths.extend(columns.iter().map(|x| Constraint::Length(x.width)));
+    let mut title = boxscore_title(app);
+    if hidden > 0 {
+        title.push_str(&format!(" - {} columns hidden", hidden));
+    }
+    if scrollable {
+        let keys = |action| {
+            app.keys
+                .keys(action)
+                .first()
+                .map(|x| x.to_string())
+                .unwrap_or_default()
+        };
+        title.push_str(&format!(
+            " - {}/{} to scroll",
+            keys(Action::ScrollLeft),
+            keys(Action::ScrollRight)
+        ));
+    }
+    let table = Table::new(rows)
+        .header(
+            Row::new(header)
+                .style(Style::default().fg(app.theme.highlight))
+                .bottom_margin(1),
+        )
+        .block(Block::default().title(title).borders(Borders::ALL))
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+        .widths(&widths);
+    // Rows start below the borders, the header and its margin
+    let rows_area = Rect::new(
+        inner.x,
+        inner.y + 2,
+        inner.width,
+        inner.height.saturating_sub(2),
+    );
+    app.player_offset = scroll_offset(app.player_offset, app.player, rows_area.height as usize);
+    app.areas.players = rows_area;
+    let mut state = app.players.clone();
+    state.select(app.player);
+    f.render_stateful_widget(table, chunks[1], &mut state);
+    app.players = state;
+}
+
+/// Width of the frozen player name column of the boxscore.
+const NAME_WIDTH: u16 = 15;
+
+/// Below this width the boxscore is shown as one card per player.
+const CARD_MODE_WIDTH: u16 = 50;
+
+/// Columns of `columns` kept in `width` cells and how many were hidden,
+/// hiding the highest priorities first.
+fn box_columns(columns: &[&'static Column], width: u16) -> (Vec<&'static Column>, usize) {
+    let mut kept = columns.to_vec();
+    for &priority in [3, 2].iter() {
+        let total: u16 = kept.iter().map(|x| x.width + 1).sum();
+        if total <= width {
+            break;
+        }
+        kept.retain(|x| x.priority < priority);
+    }
+    let hidden = columns.len() - kept.len();
+    (kept, hidden)
+}
+
+/// Title of the boxscore with the part of the game shown, noting when the
+/// play by play it is counted from disagrees with the feed.
+fn boxscore_title(app: &App) -> String {
+    let segment = match app.box_segment {
+        Some(x) => x,
+        None => return "Boxscore - Full game".to_string(),
+    };
+    let mut title = format!("Boxscore - {}", segment);
+    if app.segment_mismatches > 0 {
+        title.push_str(&format!(
+            " ({} players differ from the feed)",
+            app.segment_mismatches
+        ));
+    }
+    title
+}
+
+/// Compact boxscore for very narrow terminals, a few lines per player.
+fn draw_player_cards<B>(f: &mut Frame<B>, app: &mut App, area: Rect)
+where
+    B: Backend,
+{
+    let glyphs = glyphs(app);
+    let items: Vec<ListItem> = app
+        .team_players()
+        .iter()
+        .map(|s| {
+            let (on_court, style) = if let Some(true) = s.is_on_court {
+                (glyphs.on_court, Style::default().fg(app.theme.positive))
             } else {
-                down_style
+                (glyphs.bench, Style::default().fg(app.theme.negative))
             };
-            Some(
-                Row::new([
-                    format!("{} {}", s.first_name, s.last_name),
-                    // TODO: reomve unwraps as its not always valid
-                    s.pos.unwrap().to_string(),
-                    format!("{: >5}", s.min.unwrap_or("0")),
-                    format!("{: >5}", s.points.unwrap_or("0")),
-                    format!("{: >5}", s.tot_reb.unwrap_or("0")),
-                    format!("{: >5}", s.assists.unwrap_or("0")),
-                    format!("{: >5}", s.steals.unwrap_or("0")),
-                    format!("{: >5}", s.blocks.unwrap_or("0")),
-                    // Blocked Attempts
-                    format!("{: >5}", s.blocks.unwrap_or("0")),
-                    format!("{: >5}", s.fgm.unwrap_or("0")),
-                    format!("{: >5}", s.fga.unwrap_or("0")),
-                    format!("{: >5}", s.fgp.unwrap_or("0")),
-                    format!("{: >5}", s.tpm.unwrap_or("0")),
-                    format!("{: >5}", s.tpa.unwrap_or("0")),
-                    format!("{: >5}", s.tpp.unwrap_or("0")),
-                    format!("{: >5}", s.ftm.unwrap_or("0")),
-                    format!("{: >5}", s.fta.unwrap_or("0")),
-                    format!("{: >5}", s.ftp.unwrap_or("0")),
-                    format!("{: >5}", s.off_reb.unwrap_or("0")),
-                    format!("{: >5}", s.def_reb.unwrap_or("0")),
-                    format!("{: >5}", s.turnovers.unwrap_or("0")),
-                    format!("{: >5}", s.p_fouls.unwrap_or("0")),
-                    format!("{: >5}", s.plus_minus.unwrap_or("0")),
-                ])
-                .style(style),
-            )
+            let stats = app.player_stats(s);
+            ListItem::new(vec![
+                Spans::from(Span::styled(
+                    format!(
+                        "{}{} {} {} {}",
+                        on_court,
+                        s.first_name,
+                        s.last_name,
+                        s.pos.unwrap_or(""),
+                        columns::minutes(stats.minutes)
+                    ),
+                    style,
+                )),
+                Spans::from(format!(
+                    "  {} pts {} reb {} ast {} +/-",
+                    stats.points, stats.tot_reb, stats.assists, stats.plus_minus
+                )),
+                Spans::from(Span::styled(
+                    format!(
+                        "  FG {}-{} 3P {}-{} FT {}-{}",
+                        stats.fgm, stats.fga, stats.tpm, stats.tpa, stats.ftm, stats.fta
+                    ),
+                    Style::default().fg(app.theme.muted),
+                )),
+            ])
+        })
+        .collect();
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .title(boxscore_title(app))
+                .borders(Borders::ALL),
+        )
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+    let mut state = ListState::default();
+    state.select(app.player);
+    // Cards span several rows, clicks are not mapped to players
+    app.areas.players = Rect::default();
+    f.render_stateful_widget(list, area, &mut state);
+}
+
+const CARD_WIDTH: u16 = 24;
+const CARD_HEIGHT: u16 = 5;
+
+fn draw_scoreboard<B>(f: &mut Frame<B>, app: &mut App, area: Rect)
+where
+    B: Backend,
+{
+    let block = Block::default().borders(Borders::ALL).title("Scoreboard");
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    let games = app.date_games();
+    let columns = (inner.width / CARD_WIDTH).max(1) as usize;
+    let rows = (inner.height / CARD_HEIGHT).max(1) as usize;
+    // Scroll by whole rows to keep the selected card visible
+    let first_row = (app.scoreboard / columns).saturating_sub(rows - 1);
+    for (i, game) in games.iter().enumerate().skip(first_row * columns) {
+        let (row, column) = (i / columns - first_row, i % columns);
+        if row >= rows {
+            break;
+        }
+        let card = Rect::new(
+            inner.x + column as u16 * CARD_WIDTH,
+            inner.y + row as u16 * CARD_HEIGHT,
+            CARD_WIDTH.min(inner.width),
+            CARD_HEIGHT.min(inner.height),
+        );
+        draw_card(f, app, game, i == app.scoreboard, card);
+    }
+}
+
+fn draw_card<B>(f: &mut Frame<B>, app: &App, game: &Game, selected: bool, area: Rect)
+where
+    B: Backend,
+{
+    let series = app
+        .schedule
+        .as_ref()
+        .and_then(|schedule| schedule.series_score(game));
+    let period = game.period();
+    let status = match game.status_num() {
+        1 => app.start_time(game),
+        2 if period.is_halftime() => "Half".to_string(),
+        2 => {
+            let name = if period.current() > period.max_regular() {
+                format!("OT{}", period.current() - period.max_regular())
+            } else {
+                format!("Q{}", period.current())
+            };
+            match game.clock() {
+                "" if period.is_end_of_period() => format!("End of {}", name),
+                "" => name,
+                clock => format!("{} {}", name, clock),
+            }
+        }
+        _ if period.current() > period.max_regular() => "Final/OT".to_string(),
+        _ => "Final".to_string(),
+    };
+    let winner = game.winner();
+    let line = |tri_code: &str, team: &nil::schedule::Team| {
+        let style = if winner == Some(team.team_id()) {
+            Style::default().add_modifier(Modifier::BOLD)
+        } else {
+            Style::default()
+        };
+        let tri_code_style = style.patch(
+            app.theme
+                .team(team.team_id(), app.is_favorite(team.team_id())),
+        );
+        let score_style = if app.theme.team_colors {
+            style.fg(app.theme.team_color(team.team_id()))
+        } else {
+            style
+        };
+        let score = if game.status_num() == 1 {
+            String::new()
         } else {
-            None
+            team.score().to_string()
+        };
+        Spans::from(vec![
+            Span::styled(format!("{: <4}", tri_code), tri_code_style),
+            Span::styled(
+                format!("{: <8}", format!("{}-{}", team.win(), team.loss())),
+                Style::default().fg(app.theme.muted),
+            ),
+            Span::styled(format!("{: >6}", score), score_style),
+        ])
+    };
+    let mut text = vec![
+        line(game.v_tri_code(), game.v_team()),
+        line(game.h_tri_code(), game.h_team()),
+    ];
+    if let (Some(playoffs), Some((h_wins, v_wins))) = (game.playoffs(), series) {
+        let note = if h_wins > v_wins {
+            format!("{} leads {}-{}", game.h_tri_code(), h_wins, v_wins)
+        } else if v_wins > h_wins {
+            format!("{} leads {}-{}", game.v_tri_code(), v_wins, h_wins)
+        } else {
+            format!("Tied {}-{}", h_wins, v_wins)
+        };
+        text.push(Spans::from(Span::styled(
+            format!("G{} {}", playoffs.num_in_series(), note),
+            Style::default().fg(app.theme.accent),
+        )));
+    }
+    let border_style = if selected {
+        Style::default().fg(app.theme.highlight)
+    } else {
+        Style::default()
+    };
+    let card = Paragraph::new(text).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(border_style)
+            .title(status),
+    );
+    f.render_widget(card, area);
+}
+
+fn draw_standings<B>(f: &mut Frame<B>, app: &mut App, area: Rect)
+where
+    B: Backend,
+{
+    let standings = match &app.standings {
+        Some(x) => x,
+        None => return,
+    };
+    let direction = if area.width >= 140 {
+        Direction::Horizontal
+    } else {
+        Direction::Vertical
+    };
+    let chunks = Layout::default()
+        .direction(direction)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)].as_ref())
+        .split(area);
+    for (&conference, &area) in [Conference::East, Conference::West]
+        .iter()
+        .zip(chunks.iter())
+    {
+        if app.by_division {
+            let divisions: Vec<Division> = Division::ALL
+                .iter()
+                .copied()
+                .filter(|x| x.conference() == conference)
+                .collect();
+            let chunks = Layout::default()
+                .constraints(
+                    [
+                        Constraint::Ratio(1, 3),
+                        Constraint::Ratio(1, 3),
+                        Constraint::Ratio(1, 3),
+                    ]
+                    .as_ref(),
+                )
+                .split(area);
+            for (division, &area) in divisions.iter().zip(chunks.iter()) {
+                draw_standings_table(
+                    f,
+                    &app.theme,
+                    &division.to_string(),
+                    standings.division(*division),
+                    area,
+                );
+            }
+        } else {
+            draw_standings_table(
+                f,
+                &app.theme,
+                &conference.to_string(),
+                standings.conference(conference),
+                area,
+            );
         }
+    }
+}
+
+fn draw_standings_table<B>(
+    f: &mut Frame<B>,
+    theme: &Theme,
+    title: &str,
+    table: &[Standing],
+    area: Rect,
+) where
+    B: Backend,
+{
+    let rows = table.iter().enumerate().map(|(i, s)| {
+        Row::new([
+            format!("{: >2}", i + 1),
+            s.team.tri_code.to_string(),
+            format!("{: >3}", s.record.wins),
+            format!("{: >3}", s.record.losses),
+            format!("{: >5}", format_pct(s.record.pct())),
+            format!("{: >5}", format_games_behind(s.games_behind)),
+            s.home.to_string(),
+            s.away.to_string(),
+            s.division.to_string(),
+            s.conference.to_string(),
+            s.last_10.to_string(),
+            s.streak.map(|x| x.to_string()).unwrap_or_default(),
+        ])
     });
     let table = Table::new(rows)
         .header(
             Row::new([
-                "Player", "P", "Min", "Pts", "Reb", "Ast", "Stl", "Blk", "FGM", "FGA", "FG%",
-                "3PM", "3PA", "3P%", "FTM", "FTA", "FT%", "OREB", "DREB", "TOV", "PF", "+/-",
+                " #", "Team", "  W", "  L", "  Pct", "   GB", "Home", "Away", "Div", "Conf", "L10",
+                "Strk",
             ])
-            .style(Style::default().fg(Color::Yellow))
+            .style(Style::default().fg(theme.highlight))
             .bottom_margin(1),
         )
-        .block(Block::default().title("Boxscore").borders(Borders::ALL))
+        .block(
+            Block::default()
+                .title(title.to_string())
+                .borders(Borders::ALL),
+        )
         .widths(&[
-            // TODO: Variable lengths
-            // Kinda broken: https://github.com/fdehau/tui-rs/issues/499
-            // Constraint::Min(0),
-            Constraint::Length(15),
+            Constraint::Length(2),
+            Constraint::Length(4),
+            Constraint::Length(3),
             Constraint::Length(3),
             Constraint::Length(5),
             Constraint::Length(5),
@@ -247,19 +1076,886 @@ where
             Constraint::Length(5),
             Constraint::Length(5),
             Constraint::Length(5),
+            Constraint::Length(4),
+        ]);
+    f.render_widget(table, area);
+}
+
+const ROUNDS: [&str; 4] = ["First round", "Conf. semifinals", "Conf. finals", "Finals"];
+
+fn tri_code(team_id: &str) -> &str {
+    teams::from_id(team_id).map_or("TBD", |x| x.tri_code)
+}
+
+fn draw_bracket<B>(f: &mut Frame<B>, app: &mut App, area: Rect)
+where
+    B: Backend,
+{
+    let series = app.series();
+    if series.is_empty() {
+        let text = Paragraph::new("No playoff games scheduled yet.")
+            .alignment(Alignment::Center)
+            .block(Block::default().borders(Borders::ALL).title("Bracket"));
+        return f.render_widget(text, area);
+    }
+
+    let chunks = Layout::default()
+        .constraints([Constraint::Min(0), Constraint::Length(9)].as_ref())
+        .split(area);
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Ratio(1, 4); 4].as_ref())
+        .split(chunks[0]);
+    for (round, (&title, &area)) in ROUNDS.iter().zip(columns.iter()).enumerate() {
+        let items: Vec<ListItem> = series
+            .iter()
+            .enumerate()
+            .filter(|(_, x)| x.round as usize == round + 1)
+            .map(|(i, x)| {
+                let style = if i == app.bracket {
+                    Style::default().fg(app.theme.highlight)
+                } else {
+                    Style::default()
+                };
+                let winner = x.winner();
+                let line = |team: &str, seed: u8, wins: u8| {
+                    let style = if winner == Some(team) {
+                        style.add_modifier(Modifier::BOLD)
+                    } else if winner.is_some() {
+                        style.fg(app.theme.muted)
+                    } else {
+                        style
+                    };
+                    Spans::from(Span::styled(
+                        format!("({}) {: <4}{: >2}", seed, tri_code(team), wins),
+                        style,
+                    ))
+                };
+                ListItem::new(vec![
+                    Spans::from(Span::styled(
+                        x.conference.to_string(),
+                        Style::default().fg(app.theme.muted),
+                    )),
+                    line(x.top_team, x.top_seed, x.top_wins),
+                    line(x.bottom_team, x.bottom_seed, x.bottom_wins),
+                    Spans::from(""),
+                ])
+            })
+            .collect();
+        let list = List::new(items).block(Block::default().borders(Borders::ALL).title(title));
+        f.render_widget(list, area);
+    }
+
+    if let Some(selected) = series.get(app.bracket) {
+        draw_series_games(f, app, selected, chunks[1]);
+    }
+}
+
+fn draw_series_games<B>(f: &mut Frame<B>, app: &App, series: &Series, area: Rect)
+where
+    B: Backend,
+{
+    let items: Vec<ListItem> = series
+        .games
+        .iter()
+        .map(|game| {
+            let playoffs = game.playoffs().unwrap();
+            let (v, h) = (game.v_team(), game.h_team());
+            let text = match game.status_num() {
+                1 => format!(
+                    "G{} {} {} {} @ {}{}",
+                    playoffs.num_in_series(),
+                    game.start_date_eastern(),
+                    app.start_time(game),
+                    tri_code(v.team_id()),
+                    tri_code(h.team_id()),
+                    if playoffs.if_necessary() {
+                        " (if necessary)"
+                    } else {
+                        ""
+                    },
+                ),
+                status => format!(
+                    "G{} {} {} {: >3} @ {} {: >3}{}",
+                    playoffs.num_in_series(),
+                    game.start_date_eastern(),
+                    tri_code(v.team_id()),
+                    v.score(),
+                    tri_code(h.team_id()),
+                    h.score(),
+                    if status == 2 { " (live)" } else { "" },
+                ),
+            };
+            ListItem::new(text)
+        })
+        .collect();
+    let title = format!(
+        "{} {} vs {}",
+        series.conference,
+        tri_code(series.top_team),
+        tri_code(series.bottom_team)
+    );
+    let list = List::new(items).block(Block::default().borders(Borders::ALL).title(title));
+    f.render_widget(list, area);
+}
+
+fn draw_team_schedule<B>(f: &mut Frame<B>, app: &mut App, area: Rect)
+where
+    B: Backend,
+{
+    let team = &TEAMS[app.team];
+    let games = app.team_schedule();
+    let rows: Vec<Row> = games
+        .iter()
+        .map(|x| {
+            let (team_score, opp_score) = if x.home {
+                (x.game.h_team().score(), x.game.v_team().score())
+            } else {
+                (x.game.v_team().score(), x.game.h_team().score())
+            };
+            let result = match (x.won, x.game.status_num()) {
+                (Some(won), _) => format!(
+                    "{} {}-{}",
+                    if won { "W" } else { "L" },
+                    team_score,
+                    opp_score
+                ),
+                (None, 2) => format!("Live {}-{}", team_score, opp_score),
+                _ => app.start_time(x.game),
+            };
+            let style = match x.won {
+                Some(true) => Style::default().fg(app.theme.positive),
+                Some(false) => Style::default().fg(app.theme.negative),
+                None => Style::default(),
+            };
+            Row::new([
+                x.game.start_date_eastern().to_string(),
+                format!(
+                    "{} {}",
+                    if x.home { "vs" } else { "@" },
+                    tri_code(x.opponent())
+                ),
+                result,
+                x.record.to_string(),
+                x.streak.map(|x| x.to_string()).unwrap_or_default(),
+            ])
+            .style(style)
+        })
+        .collect();
+    let key = |action| app.keys.keys(action).first().map(|x| x.to_string());
+    let mut hints = Vec::new();
+    if let (Some(prev), Some(next)) = (key(Action::PrevTeam), key(Action::NextTeam)) {
+        hints.push(format!("{}/{} to change team", prev, next));
+    }
+    if let Some(select) = key(Action::Select) {
+        hints.push(format!("{} to open a game", select));
+    }
+    let mut title = format!("{} {}", team.city, team.nickname);
+    if !hints.is_empty() {
+        title.push_str(&format!(" - {}", hints.join(", ")));
+    }
+    let table = Table::new(rows)
+        .header(
+            Row::new(["Date", "Opp", "Result", "Record", "Strk"])
+                .style(Style::default().fg(app.theme.highlight))
+                .bottom_margin(1),
+        )
+        .block(Block::default().title(title).borders(Borders::ALL))
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+        .widths(&[
+            Constraint::Length(8),
+            Constraint::Length(6),
+            Constraint::Length(12),
+            Constraint::Length(7),
+            Constraint::Length(4),
+        ]);
+    let mut state = TableState::default();
+    if !games.is_empty() {
+        state.select(Some(app.team_game()));
+    }
+    f.render_stateful_widget(table, area, &mut state);
+}
+
+/// Areas of the titles of a bordered `Tabs` widget, padding included.
+fn tab_areas(area: Rect, titles: &[&str]) -> Vec<Rect> {
+    let inner = Block::default().borders(Borders::ALL).inner(area);
+    let mut x = inner.x;
+    titles
+        .iter()
+        .map(|title| {
+            let width = title.chars().count() as u16 + 2;
+            let rect = Rect::new(x, inner.y, width.min(inner.right().saturating_sub(x)), 1);
+            // One cell for the divider
+            x = x.saturating_add(width + 1);
+            rect
+        })
+        .collect()
+}
+
+/// A stat of the team comparison.
+enum Comparison {
+    /// A stat of both teams, visitor first, with a gauge of their shares
+    /// when it has `values`.
+    Teams {
+        label: String,
+        values: Option<[f32; 2]>,
+        texts: [String; 2],
+    },
+    /// A stat of the whole game.
+    Game { label: String, value: String },
+}
+
+impl Comparison {
+    fn count(label: &str, values: [u16; 2]) -> Self {
+        Comparison::Teams {
+            label: label.to_string(),
+            values: Some([values[0] as f32, values[1] as f32]),
+            texts: [values[0].to_string(), values[1].to_string()],
+        }
+    }
+
+    fn split(label: &str, totals: &[Stats; 2], split: fn(&Stats) -> (u16, u16)) -> Self {
+        let values = [split(&totals[0]), split(&totals[1])];
+        let pct = |(made, attempted): (u16, u16)| {
+            if attempted == 0 {
+                0.0
+            } else {
+                100.0 * made as f32 / attempted as f32
+            }
+        };
+        let text =
+            |(made, attempted)| format!("{}-{} {:.1}%", made, attempted, pct((made, attempted)));
+        Comparison::Teams {
+            label: label.to_string(),
+            values: Some([pct(values[0]), pct(values[1])]),
+            texts: [text(values[0]), text(values[1])],
+        }
+    }
+
+    fn ratio(label: &str, values: [f32; 2], precision: usize) -> Self {
+        Comparison::Teams {
+            label: label.to_string(),
+            values: Some(values),
+            texts: [
+                format!("{:.*}", precision, values[0]),
+                format!("{:.*}", precision, values[1]),
+            ],
+        }
+    }
+}
+
+const COMPARISON_TEXT_WIDTH: u16 = 14;
+const COMPARISON_LABEL_WIDTH: u16 = 18;
+
+/// Sections of the team comparison, titles with their stats.
+type Sections = Vec<(String, Vec<Comparison>)>;
+
+/// Shooting, rebounds, ball control, points and four factors from the team
+/// totals of the boxscore.
+fn totals_sections(stats: &GameStats) -> Sections {
+    let teams = [stats.v_team(), stats.h_team()];
+    let totals = [
+        Stats::from(teams[0].totals()),
+        Stats::from(teams[1].totals()),
+    ];
+    let factors = [
+        FourFactors::new(&totals[0], &totals[1]),
+        FourFactors::new(&totals[1], &totals[0]),
+    ];
+    let counts = |label, stat: fn(&Stats) -> u16| {
+        Comparison::count(label, [stat(&totals[0]), stat(&totals[1])])
+    };
+
+    let points = [
+        (
+            "Fast break",
+            [teams[0].fast_break_points(), teams[1].fast_break_points()],
+        ),
+        (
+            "In the paint",
+            [teams[0].points_in_paint(), teams[1].points_in_paint()],
+        ),
+        (
+            "Second chance",
+            [
+                teams[0].second_chance_points(),
+                teams[1].second_chance_points(),
+            ],
+        ),
+        (
+            "Off turnovers",
+            [
+                teams[0].points_off_turnovers(),
+                teams[1].points_off_turnovers(),
+            ],
+        ),
+    ];
+    // Only shown when the feed has them
+    let points = points
+        .iter()
+        .filter_map(|(label, values)| match values {
+            [Some(v), Some(h)] => {
+                Some(Comparison::count(label, [v.parse().ok()?, h.parse().ok()?]))
+            }
+            _ => None,
+        })
+        .collect();
+    vec![
+        (
+            "Shooting".to_string(),
+            vec![
+                Comparison::split("Field goals", &totals, |x| (x.fgm, x.fga)),
+                Comparison::split("Three pointers", &totals, |x| (x.tpm, x.tpa)),
+                Comparison::split("Free throws", &totals, |x| (x.ftm, x.fta)),
+            ],
+        ),
+        (
+            "Rebounds".to_string(),
+            vec![
+                counts("Offensive", |x| x.off_reb),
+                counts("Defensive", |x| x.def_reb),
+                counts("Total", |x| x.tot_reb),
+            ],
+        ),
+        (
+            "Ball".to_string(),
+            vec![
+                counts("Assists", |x| x.assists),
+                counts("Turnovers", |x| x.turnovers),
+                counts("Steals", |x| x.steals),
+                counts("Blocks", |x| x.blocks),
+            ],
+        ),
+        ("Points".to_string(), points),
+        (
+            "Four factors".to_string(),
+            vec![
+                Comparison::ratio("eFG%", [factors[0].efg, factors[1].efg], 1),
+                Comparison::ratio("TOV%", [factors[0].tov, factors[1].tov], 1),
+                Comparison::ratio("ORB%", [factors[0].orb, factors[1].orb], 1),
+                Comparison::ratio("FT rate", [factors[0].ft_rate, factors[1].ft_rate], 3),
+            ],
+        ),
+    ]
+}
+
+/// Lead changes, largest leads, runs and time leading, from the boxscore
+/// when it has them and from the play by play otherwise.
+fn flow_sections(app: &App, stats: Option<&GameStats>) -> Sections {
+    let flow = &app.flow;
+    let plays = &app.plays.items;
+    let (lead_changes, times_tied) = match stats {
+        Some(x) => (x.lead_changes().to_string(), x.times_tied().to_string()),
+        None => (flow.lead_changes.to_string(), flow.times_tied.to_string()),
+    };
+    let longest_run = match stats {
+        Some(x) => [
+            x.v_team().longest_run().parse().unwrap_or(0),
+            x.h_team().longest_run().parse().unwrap_or(0),
+        ],
+        None => {
+            let runs = pbp::runs(plays, 1);
+            let longest = |home| {
+                runs.iter()
+                    .filter(|x| x.home == home)
+                    .map(|x| x.points as u16)
+                    .max()
+                    .unwrap_or(0)
+            };
+            [longest(false), longest(true)]
+        }
+    };
+    let game_wide = |label: &str, value| Comparison::Game {
+        label: label.to_string(),
+        value,
+    };
+    let minutes = |x: f32| format!("{}:{:02}", x as u32, (x.fract() * 60.0) as u32);
+    let time_leading = Comparison::Teams {
+        label: "Time leading".to_string(),
+        values: Some([flow.v_time_leading, flow.h_time_leading]),
+        texts: [minutes(flow.v_time_leading), minutes(flow.h_time_leading)],
+    };
+    let runs = flow
+        .runs
+        .iter()
+        .map(|run| {
+            let text = format!("{}-0", run.points);
+            let (start, end) = (&plays[run.start], &plays[run.end]);
+            Comparison::Teams {
+                label: format!(
+                    "{} {}-{} {}",
+                    pbp::period_name(start.period),
+                    start.clock,
+                    pbp::period_name(end.period),
+                    end.clock
+                ),
+                values: None,
+                texts: if run.home {
+                    [String::new(), text]
+                } else {
+                    [text, String::new()]
+                },
+            }
+        })
+        .collect();
+    vec![
+        (
+            "Game flow".to_string(),
+            vec![
+                game_wide("Lead changes", lead_changes),
+                game_wide("Times tied", times_tied),
+                Comparison::count(
+                    "Largest lead",
+                    [flow.v_largest_lead as u16, flow.h_largest_lead as u16],
+                ),
+                Comparison::count("Longest run", longest_run),
+                time_leading,
+            ],
+        ),
+        ("Runs".to_string(), runs),
+    ]
+}
+
+/// Side by side team totals, four factors and game flow of the current game.
+fn draw_compare<B>(f: &mut Frame<B>, app: &App, area: Rect)
+where
+    B: Backend,
+{
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title("Team comparison");
+    let boxscore = match &app.boxscore {
+        Some(b) => b,
+        None => return,
+    };
+    let stats = boxscore.stats();
+    if stats.is_none() && app.plays.items.is_empty() {
+        let para = Paragraph::new("Available after tip-off")
+            .block(block)
+            .alignment(Alignment::Center);
+        return f.render_widget(para, area);
+    }
+    let mut sections = stats.map(totals_sections).unwrap_or_default();
+    sections.extend(flow_sections(app, stats));
+
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(
+            [
+                Constraint::Length(COMPARISON_LABEL_WIDTH),
+                Constraint::Length(COMPARISON_TEXT_WIDTH),
+                Constraint::Length(1),
+                Constraint::Min(0),
+                Constraint::Length(1),
+                Constraint::Length(COMPARISON_TEXT_WIDTH),
+            ]
+            .as_ref(),
+        )
+        .split(Rect { height: 1, ..inner });
+    let [label_area, v_area, gauge_area, h_area] = [columns[0], columns[1], columns[3], columns[5]];
+    let teams = [&boxscore.v_team, &boxscore.h_team];
+    let colors = teams.map(|x| app.theme.team_color(x.team_id));
+    let heading = Style::default()
+        .fg(app.theme.highlight)
+        .add_modifier(Modifier::BOLD);
+    let at = |area: Rect, y| Rect { y, ..area };
+    let text = |x: &str, alignment| Paragraph::new(x.to_string()).alignment(alignment);
+
+    if inner.height > 0 {
+        for (team, area, alignment) in [
+            (teams[0], v_area, Alignment::Right),
+            (teams[1], h_area, Alignment::Left),
+        ] {
+            let style = app.theme.team(team.team_id, app.is_favorite(team.team_id));
+            let para = Paragraph::new(Span::styled(team.tri_code, style)).alignment(alignment);
+            f.render_widget(para, at(area, inner.y));
+        }
+    }
+    // Lines below the header, drawn until the area is full
+    let mut lines = inner.y + 2..inner.bottom();
+    'sections: for (title, comparisons) in sections.iter().filter(|(_, x)| !x.is_empty()) {
+        let y = match lines.next() {
+            Some(y) => y,
+            None => break,
+        };
+        let para = Paragraph::new(Span::styled(title.as_str(), heading));
+        f.render_widget(para, at(label_area, y));
+        for x in comparisons {
+            let y = match lines.next() {
+                Some(y) => y,
+                None => break 'sections,
+            };
+            match x {
+                Comparison::Teams {
+                    label,
+                    values,
+                    texts,
+                } => {
+                    f.render_widget(text(label, Alignment::Left), at(label_area, y));
+                    f.render_widget(text(&texts[0], Alignment::Right), at(v_area, y));
+                    f.render_widget(text(&texts[1], Alignment::Left), at(h_area, y));
+                    let total = values.map_or(0.0, |x| x[0] + x[1]);
+                    if let Some([v, _]) = values.filter(|_| total > 0.0) {
+                        // Share of the visitor from the left, of the home
+                        // team from the right
+                        let gauge = Gauge::default()
+                            .ratio(f64::from(v / total))
+                            .label("")
+                            .gauge_style(Style::default().fg(colors[0]).bg(colors[1]))
+                            .use_unicode(app.enhanced_graphics);
+                        f.render_widget(gauge, at(gauge_area, y));
+                    }
+                }
+                Comparison::Game { label, value } => {
+                    f.render_widget(text(label, Alignment::Left), at(label_area, y));
+                    let value_area = Rect {
+                        width: inner.right() - v_area.x,
+                        ..v_area
+                    };
+                    f.render_widget(text(value, Alignment::Left), at(value_area, y));
+                }
+            }
+        }
+    }
+}
+
+fn format_rating(rating: Option<f32>) -> String {
+    rating.map_or("-".to_string(), |x| format!("{:+.1}", x))
+}
+
+/// Five-man units of the selected team and the on/off splits of its
+/// players, reconstructed from substitutions.
+fn draw_lineups<B>(f: &mut Frame<B>, app: &mut App, area: Rect)
+where
+    B: Backend,
+{
+    let chunks = Layout::default()
+        .constraints(
+            [
+                Constraint::Length(3),
+                Constraint::Percentage(55),
+                Constraint::Min(0),
+            ]
+            .as_ref(),
+        )
+        .split(area);
+    draw_team_tabs(f, app, chunks[0]);
+    let home = app.tabs.team == TabTeam::Home;
+    let header_style = Style::default().fg(app.theme.highlight);
+    let plus_minus_style = |x: i32| match x {
+        x if x > 0 => Style::default().fg(app.theme.positive),
+        x if x < 0 => Style::default().fg(app.theme.negative),
+        _ => Style::default(),
+    };
+
+    let rows = app.lineups.team(home).map(|lineup| {
+        let names: Vec<&str> = lineup.players.iter().map(|x| app.last_name(x)).collect();
+        let stint = &lineup.stint;
+        Row::new(vec![
+            names.join(", "),
+            format!("{:>5.1}", stint.minutes),
+            format!("{:>4}", stint.points_for),
+            format!("{:>4}", stint.points_against),
+            format!("{:>+4}", stint.plus_minus()),
+            format!("{:>6}", format_rating(stint.net_rating())),
+        ])
+        .style(plus_minus_style(stint.plus_minus()))
+    });
+    let title = if app.lineups.lineups.is_empty() {
+        "Lineups - No substitutions yet"
+    } else {
+        "Lineups"
+    };
+    let table = Table::new(rows)
+        .header(
+            Row::new(vec!["Players", "  Min", "  PF", "  PA", " +/-", "   Net"])
+                .style(header_style)
+                .bottom_margin(1),
+        )
+        .block(Block::default().borders(Borders::ALL).title(title))
+        .widths(&[
+            Constraint::Min(20),
             Constraint::Length(5),
+            Constraint::Length(4),
+            Constraint::Length(4),
+            Constraint::Length(4),
+            Constraint::Length(6),
+        ]);
+    f.render_widget(table, chunks[1]);
+
+    let mut on_off: Vec<_> = app
+        .lineups
+        .on_off
+        .iter()
+        .filter(|x| x.home == home)
+        .collect();
+    on_off.sort_by(|a, b| b.on.minutes.total_cmp(&a.on.minutes));
+    let rows = on_off.iter().map(|x| {
+        let diff = match (x.on.net_rating(), x.off.net_rating()) {
+            (Some(on), Some(off)) => Some(on - off),
+            _ => None,
+        };
+        Row::new(vec![
+            app.last_name(x.person_id).to_string(),
+            format!("{:>5.1}", x.on.minutes),
+            format!("{:>+4}", x.on.plus_minus()),
+            format!("{:>6}", format_rating(x.on.net_rating())),
+            format!("{:>6}", format_rating(x.off.net_rating())),
+            format!("{:>6}", format_rating(diff)),
+        ])
+    });
+    let table = Table::new(rows)
+        .header(
+            Row::new(vec![
+                "Player", "  Min", " +/-", "On Net", "OffNet", "On-Off",
+            ])
+            .style(header_style)
+            .bottom_margin(1),
+        )
+        .block(Block::default().borders(Borders::ALL).title("On/off"))
+        .widths(&[
+            Constraint::Min(15),
             Constraint::Length(5),
-            Constraint::Length(5),
-            Constraint::Length(5),
-            Constraint::Length(5),
-            Constraint::Length(5),
-            Constraint::Length(5),
-            Constraint::Length(5),
-            Constraint::Length(5),
-            Constraint::Length(5),
-            Constraint::Length(5),
+            Constraint::Length(4),
+            Constraint::Length(6),
+            Constraint::Length(6),
+            Constraint::Length(6),
+        ]);
+    f.render_widget(table, chunks[2]);
+}
+
+/// Stretches on the court of the players of the selected team, one row per
+/// player across the periods of the game.
+fn draw_rotation<B>(f: &mut Frame<B>, app: &mut App, area: Rect)
+where
+    B: Backend,
+{
+    let chunks = Layout::default()
+        .constraints([Constraint::Length(3), Constraint::Min(0)].as_ref())
+        .split(area);
+    draw_team_tabs(f, app, chunks[0]);
+    let home = app.tabs.team == TabTeam::Home;
+    let plays = &app.plays.items;
+    let periods = pbp::periods(plays);
+    let length = pbp::period_start(periods + 1);
+    let block = Block::default().borders(Borders::ALL).title("Rotation");
+    let width = block.inner(chunks[1]).width.saturating_sub(NAME_WIDTH + 7) as usize;
+    if width < periods as usize {
+        return f.render_widget(block, chunks[1]);
+    }
+    let cell = length / width as f32;
+    let column = |minutes: f32| (minutes / cell).round() as usize;
+    let glyphs = glyphs(app);
+    let muted = Style::default().fg(app.theme.muted);
+    let on_style = Style::default().fg(app.theme.team_color(app.get_current_team()));
+
+    let mut header = vec![' '; width];
+    for period in 1..=periods {
+        let label = pbp::period_name(period);
+        let start = column(pbp::period_start(period));
+        if start + label.len() <= width {
+            for (i, c) in label.chars().enumerate() {
+                header[start + i] = c;
+            }
+        }
+    }
+    let header: String = header.into_iter().collect();
+    let mut text = vec![Spans::from(vec![
+        Span::raw(format!("{:1$}", "", NAME_WIDTH as usize + 1)),
+        Span::styled(header, Style::default().fg(app.theme.highlight)),
+    ])];
+
+    let boundaries: Vec<usize> = (2..=periods)
+        .map(|x| column(pbp::period_start(x)))
+        .collect();
+    for rotation in app.lineups.rotations.iter().filter(|x| x.home == home) {
+        let mut spans = vec![Span::raw(format!(
+            "{:1$.1$} ",
+            app.last_name(rotation.person_id),
+            NAME_WIDTH as usize
+        ))];
+        // Consecutive cells of the same kind make a single span
+        let mut run = String::new();
+        let mut run_on = false;
+        for i in 0..width {
+            let middle = (i as f32 + 0.5) * cell;
+            let on = rotation
+                .stints
+                .iter()
+                .any(|&(start, end)| start <= middle && middle < end);
+            let symbol = if on {
+                glyphs.bar
+            } else if boundaries.contains(&i) {
+                glyphs.separator
+            } else {
+                " "
+            };
+            if on != run_on && !run.is_empty() {
+                let style = if run_on { on_style } else { muted };
+                spans.push(Span::styled(std::mem::take(&mut run), style));
+            }
+            run_on = on;
+            run.push_str(symbol);
+        }
+        spans.push(Span::styled(run, if run_on { on_style } else { muted }));
+        let minutes: f32 = rotation.stints.iter().map(|(start, end)| end - start).sum();
+        spans.push(Span::raw(format!(" {:>5.1}", minutes)));
+        text.push(Spans::from(spans));
+    }
+    if text.len() == 1 {
+        text.push(Spans::from(Span::styled("No substitutions yet", muted)));
+    }
+    f.render_widget(Paragraph::new(text).block(block), chunks[1]);
+}
+
+/// Points of the arc of a circle, angles in degrees from the x axis.
+fn arc(x: f64, y: f64, radius: f64, from: f64, to: f64) -> Vec<(f64, f64)> {
+    let steps = ((to - from) * radius / 20.0).abs().max(8.0) as usize;
+    (0..=steps)
+        .map(|i| {
+            let angle = (from + (to - from) * i as f64 / steps as f64).to_radians();
+            (x + radius * angle.cos(), y + radius * angle.sin())
+        })
+        .collect()
+}
+
+/// Lines of a half court, in tenths of feet from the basket.
+fn draw_court(ctx: &mut Context, color: Color) {
+    let line = |x1, y1, x2, y2| Line {
+        x1,
+        y1,
+        x2,
+        y2,
+        color,
+    };
+    ctx.draw(&Rectangle {
+        x: -250.0,
+        y: -52.5,
+        width: 500.0,
+        height: 470.0,
+        color,
+    });
+    // Paint and backboard
+    ctx.draw(&Rectangle {
+        x: -80.0,
+        y: -52.5,
+        width: 160.0,
+        height: 190.0,
+        color,
+    });
+    ctx.draw(&line(-30.0, -7.5, 30.0, -7.5));
+    // Corner threes meeting the arc
+    let (x, y) = (CORNER_THREE_X as f64, CORNER_THREE_Y as f64);
+    ctx.draw(&line(-x, -52.5, -x, y));
+    ctx.draw(&line(x, -52.5, x, y));
+    for coords in [
+        arc(0.0, 0.0, 7.5, 0.0, 360.0),
+        arc(0.0, 0.0, 40.0, 0.0, 180.0),
+        arc(0.0, 0.0, 237.5, 22.0, 158.0),
+        arc(0.0, 137.5, 60.0, 0.0, 180.0),
+        arc(0.0, 417.5, 60.0, 180.0, 360.0),
+    ]
+    .iter()
+    {
+        ctx.draw(&Points {
+            coords: coords.as_slice(),
+            color,
+        });
+    }
+}
+
+/// Made and missed shots of the selected team or player on a half court,
+/// with the field goal percentage of each zone.
+fn draw_shots<B>(f: &mut Frame<B>, app: &mut App, area: Rect)
+where
+    B: Backend,
+{
+    let chunks = Layout::default()
+        .constraints([Constraint::Length(3), Constraint::Min(0)].as_ref())
+        .split(area);
+    draw_team_tabs(f, app, chunks[0]);
+    let body = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Min(0), Constraint::Length(34)].as_ref())
+        .split(chunks[1]);
+
+    let shots: Vec<_> = app.shots().into_iter().filter_map(|x| x.shot).collect();
+    let shooter = app
+        .shooter
+        .and_then(|i| app.shooters().get(i).copied())
+        .map_or("All players", |x| app.last_name(x));
+    let period = app
+        .shot_period
+        .map_or("All periods".to_string(), |x| x.to_string());
+    let key = app
+        .keys
+        .keys(Action::NextPeriod)
+        .first()
+        .map(|x| format!(" - {} to filter", x))
+        .unwrap_or_default();
+    let title = format!("Shots - {} - {}{}", shooter, period, key);
+
+    let glyphs = glyphs(app);
+    let muted = app.theme.muted;
+    let (made_color, missed_color) = (
+        app.theme.team_color(app.get_current_team()),
+        app.theme.negative,
+    );
+    let canvas = Canvas::default()
+        .block(Block::default().borders(Borders::ALL).title(title))
+        .marker(glyphs.marker)
+        .x_bounds([-250.0, 250.0])
+        .y_bounds([-52.5, 417.5])
+        .paint(|ctx| {
+            draw_court(ctx, muted);
+            ctx.layer();
+            for shot in shots.iter() {
+                let (symbol, color) = if shot.made {
+                    (glyphs.made, made_color)
+                } else {
+                    (glyphs.missed, missed_color)
+                };
+                ctx.print(shot.x as f64, shot.y as f64, symbol, color);
+            }
+        });
+    f.render_widget(canvas, body[0]);
+
+    let zone_row = |name: String, shots: Vec<&Shot>| {
+        let made = shots.iter().filter(|x| x.made).count();
+        let pct = if shots.is_empty() {
+            "-".to_string()
+        } else {
+            format!("{:.1}", 100.0 * made as f32 / shots.len() as f32)
+        };
+        Row::new(vec![
+            name,
+            format!("{:>5}", format!("{}-{}", made, shots.len())),
+            format!("{:>5}", pct),
+        ])
+    };
+    let mut rows: Vec<Row> = Zone::ALL
+        .iter()
+        .map(|&zone| {
+            zone_row(
+                zone.to_string(),
+                shots.iter().filter(|x| x.zone() == zone).collect(),
+            )
+        })
+        .collect();
+    rows.push(zone_row("Total".to_string(), shots.iter().collect()));
+    let table = Table::new(rows)
+        .header(
+            Row::new(vec!["Zone", "  FG", "  FG%"])
+                .style(Style::default().fg(app.theme.highlight))
+                .bottom_margin(1),
+        )
+        .block(Block::default().borders(Borders::ALL).title("Zones"))
+        .widths(&[
+            Constraint::Length(18),
             Constraint::Length(5),
             Constraint::Length(5),
         ]);
-    f.render_widget(table, chunks[1]);
+    f.render_widget(table, body[1]);
 }
@@ -1,14 +1,55 @@
+use crate::Error;
 use chrono::prelude::*;
+use chrono_tz::{America::New_York, Tz};
 use serde::de::Deserialize;
 use serde_derive::Deserialize;
-use tui::widgets::ListState;
+use std::str::FromStr;
 
+/// Today's date in the `yyyymmdd` format used by the feeds.
+///
+/// Games are keyed by their Eastern start date, so today is the current
+/// date in Eastern time wherever the user is.
 pub fn today() -> String {
-    let local: DateTime<Local> = Local::now();
-    local.format("%Y%m%d").to_string()
+    Utc::now()
+        .with_timezone(&New_York)
+        .format("%Y%m%d")
+        .to_string()
 }
 
-#[derive(Deserialize, Debug)]
+/// Timezone used to display times, either the system one or a tz database
+/// name such as `Europe/Paris`.
+#[derive(Debug, Clone, Copy)]
+pub enum TimeZone {
+    /// The timezone of the system.
+    Local,
+    /// A tz database timezone.
+    Named(Tz),
+}
+
+impl TimeZone {
+    /// Formats `time` in this timezone with a `strftime` format.
+    pub fn format(&self, time: &DateTime<Utc>, fmt: &str) -> String {
+        match self {
+            TimeZone::Local => time.with_timezone(&Local).format(fmt).to_string(),
+            TimeZone::Named(tz) => time.with_timezone(tz).format(fmt).to_string(),
+        }
+    }
+}
+
+impl FromStr for TimeZone {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("local") {
+            Ok(TimeZone::Local)
+        } else {
+            s.parse().map(TimeZone::Named)
+        }
+    }
+}
+
+/// Game clock period as reported by the schedule and boxscore feeds.
+#[derive(Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Period {
     current: usize,
@@ -18,104 +59,216 @@ pub struct Period {
     is_end_of_period: Option<bool>,
 }
 
+impl Period {
+    /// Current period, `0` before tip-off.
+    pub fn current(&self) -> usize {
+        self.current
+    }
+
+    /// Period type, `0` for regulation and `1` for overtime.
+    pub fn period_type(&self) -> usize {
+        self.r#type
+    }
+
+    /// Number of regulation periods, normally `4`.
+    pub fn max_regular(&self) -> usize {
+        self.max_regular
+    }
+
+    /// Whether the game is at halftime.
+    pub fn is_halftime(&self) -> bool {
+        self.is_halftime.unwrap_or(false)
+    }
+
+    /// Whether the clock ran out and the next period has not started.
+    pub fn is_end_of_period(&self) -> bool {
+        self.is_end_of_period.unwrap_or(false)
+    }
+}
+
+/// Team totals from the boxscore feed.
 #[derive(Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 #[serde(bound(deserialize = "'de: 'lf"))]
 pub struct Totals<'lf> {
-    points: &'lf str,
-    fgm: &'lf str,
-    fga: &'lf str,
-    fgp: &'lf str,
-    ftm: &'lf str,
-    fta: &'lf str,
-    ftp: &'lf str,
-    tpm: &'lf str,
-    tpa: &'lf str,
-    tpp: &'lf str,
-    off_reb: &'lf str,
-    def_reb: &'lf str,
-    tot_reb: &'lf str,
-    assists: &'lf str,
-    p_fouls: &'lf str,
-    steals: &'lf str,
-    turnovers: &'lf str,
-    blocks: &'lf str,
-    plus_minus: &'lf str,
-    min: &'lf str,
-    short_timeout_remaining: Option<&'lf str>,
-    full_timeout_remaining: Option<&'lf str>,
-    team_fouls: Option<&'lf str>,
+    /// Points scored.
+    pub points: &'lf str,
+    /// Field goals made.
+    pub fgm: &'lf str,
+    /// Field goals attempted.
+    pub fga: &'lf str,
+    /// Field goal percentage.
+    pub fgp: &'lf str,
+    /// Free throws made.
+    pub ftm: &'lf str,
+    /// Free throws attempted.
+    pub fta: &'lf str,
+    /// Free throw percentage.
+    pub ftp: &'lf str,
+    /// Three pointers made.
+    pub tpm: &'lf str,
+    /// Three pointers attempted.
+    pub tpa: &'lf str,
+    /// Three point percentage.
+    pub tpp: &'lf str,
+    /// Offensive rebounds.
+    pub off_reb: &'lf str,
+    /// Defensive rebounds.
+    pub def_reb: &'lf str,
+    /// Total rebounds.
+    pub tot_reb: &'lf str,
+    /// Assists.
+    pub assists: &'lf str,
+    /// Personal fouls.
+    pub p_fouls: &'lf str,
+    /// Steals.
+    pub steals: &'lf str,
+    /// Turnovers.
+    pub turnovers: &'lf str,
+    /// Blocked shots.
+    pub blocks: &'lf str,
+    /// Point differential of the team.
+    pub plus_minus: &'lf str,
+    /// Minutes played by the players of the team, e.g. `240`.
+    pub min: &'lf str,
+    /// Short timeouts left, only while the game is live.
+    pub short_timeout_remaining: Option<&'lf str>,
+    /// Full timeouts left, only while the game is live.
+    pub full_timeout_remaining: Option<&'lf str>,
+    /// Team fouls of the current period, only while the game is live.
+    pub team_fouls: Option<&'lf str>,
 }
 
+/// Points, rebounds and assists leaders of a team.
 #[derive(Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 #[serde(bound(deserialize = "'de: 'lf"))]
-struct Leaders<'lf> {
-    points: Stat<'lf>,
-    rebounds: Stat<'lf>,
-    assists: Stat<'lf>,
+pub struct Leaders<'lf> {
+    /// Players with the most points.
+    pub points: Stat<'lf>,
+    /// Players with the most rebounds.
+    pub rebounds: Stat<'lf>,
+    /// Players with the most assists.
+    pub assists: Stat<'lf>,
 }
 
+/// A leading stat value and the players sharing it.
 #[derive(Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 #[serde(bound(deserialize = "'de: 'lf"))]
-struct Stat<'lf> {
-    value: &'lf str,
-    players: Vec<Player<'lf>>,
+pub struct Stat<'lf> {
+    /// The leading value, e.g. `32`.
+    pub value: &'lf str,
+    /// Players sharing the leading value.
+    pub players: Vec<Player<'lf>>,
 }
 
+/// A player, either from a boxscore or from the league roster.
+///
+/// Stats are kept as the raw strings of the feed and are `None` when the
+/// player came from the roster.
 #[derive(Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 #[serde(bound(deserialize = "'de: 'lf"))]
 pub struct Player<'lf> {
     person_id: &'lf str,
+    /// First name.
     pub first_name: &'lf str,
+    /// Last name.
     pub last_name: &'lf str,
     jersey: Option<&'lf str>,
+    /// Id of the team of the player.
     pub team_id: Option<&'lf str>,
+    /// Whether the player is on the court, `None` for roster players.
     pub is_on_court: Option<bool>,
+    /// Points scored.
     pub points: Option<&'lf str>,
+    /// Position, e.g. `F-C`.
     pub pos: Option<&'lf str>,
     position_full: Option<&'lf str>,
     player_code: Option<&'lf str>,
+    /// Minutes played, as `mm:ss`.
     pub min: Option<&'lf str>,
+    /// Field goals made.
     pub fgm: Option<&'lf str>,
+    /// Field goals attempted.
     pub fga: Option<&'lf str>,
+    /// Field goal percentage.
     pub fgp: Option<&'lf str>,
+    /// Free throws made.
     pub ftm: Option<&'lf str>,
+    /// Free throws attempted.
     pub fta: Option<&'lf str>,
+    /// Free throw percentage.
     pub ftp: Option<&'lf str>,
+    /// Three pointers made.
     pub tpm: Option<&'lf str>,
+    /// Three pointers attempted.
     pub tpa: Option<&'lf str>,
+    /// Three point percentage.
     pub tpp: Option<&'lf str>,
+    /// Offensive rebounds.
     pub off_reb: Option<&'lf str>,
+    /// Defensive rebounds.
     pub def_reb: Option<&'lf str>,
+    /// Total rebounds.
     pub tot_reb: Option<&'lf str>,
+    /// Assists.
     pub assists: Option<&'lf str>,
+    /// Personal fouls.
     pub p_fouls: Option<&'lf str>,
+    /// Steals.
     pub steals: Option<&'lf str>,
+    /// Turnovers.
     pub turnovers: Option<&'lf str>,
+    /// Blocked shots.
     pub blocks: Option<&'lf str>,
+    /// Shots of the player blocked by the other team.
+    pub blocks_against: Option<&'lf str>,
+    /// Point differential while on the court.
     pub plus_minus: Option<&'lf str>,
+    /// Reason the player did not play, empty when they did.
     pub dnp: Option<&'lf str>,
+    /// Rank of the player for each stat.
     pub sort_key: Option<SortKey>,
 }
 
+const ROSTER_URL: &str = "http://data.nba.com/10s//prod/v1/2020/players.json";
+
 impl<'lf> Player<'lf> {
-    pub fn from_team_id(team_id: &str) -> Vec<Self> {
-        let json = Box::leak::<'lf>(Box::new(
-            reqwest::blocking::get("http://data.nba.com/10s//prod/v1/2020/players.json")
-                .expect("Could not fetch roster json")
-                .text()
-                .unwrap(),
-        ));
+    /// Fetches every player of the league roster, blocking version of
+    /// `fetch_roster`.
+    pub fn roster(client: &reqwest::blocking::Client) -> Result<Vec<Self>, Error> {
+        let json = Box::leak::<'lf>(Box::new(client.get(ROSTER_URL).send()?.text()?));
+
+        Ok(serde_json::from_str::<Roster<'lf>>(json)?.players)
+    }
+
+    /// Fetches every player of the league roster.
+    pub async fn fetch_roster(client: &reqwest::Client) -> Result<Vec<Self>, Error> {
+        let json = Box::leak::<'lf>(Box::new(client.get(ROSTER_URL).send().await?.text().await?));
+
+        Ok(serde_json::from_str::<Roster<'lf>>(json)?.players)
+    }
+
+    /// Id of the player, shared by every feed.
+    pub fn person_id(&self) -> &'lf str {
+        self.person_id
+    }
+
+    /// Jersey number.
+    pub fn jersey(&self) -> Option<&'lf str> {
+        self.jersey
+    }
+
+    /// Full position name, e.g. `Forward-Center`.
+    pub fn position_full(&self) -> Option<&'lf str> {
+        self.position_full
+    }
 
-        let roster = serde_json::from_str::<Roster<'lf>>(json).unwrap();
-        roster
-            .players
-            .into_iter()
-            .filter(|x| x.team_id.unwrap() == team_id)
-            .collect()
+    /// Url slug of the player, e.g. `lebron_james`.
+    pub fn player_code(&self) -> Option<&'lf str> {
+        self.player_code
     }
 }
 
@@ -152,113 +305,52 @@ where
     }
 }
 
+/// Rank of a player within the boxscore for each stat, `1` being the best.
 #[derive(Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct SortKey {
-    name: usize,
-    pos: usize,
-    points: usize,
-    min: usize,
-    fgm: usize,
-    fga: usize,
-    fgp: usize,
-    ftm: usize,
-    fta: usize,
-    ftp: usize,
-    tpm: usize,
-    tpa: usize,
-    tpp: usize,
-    off_reb: usize,
-    def_reb: usize,
-    tot_reb: usize,
-    assists: usize,
-    p_fouls: usize,
-    steals: usize,
-    turnovers: usize,
-    blocks: usize,
-    plus_minus: usize,
-}
-
-pub const TAB_NUM: usize = 2;
-
-#[derive(Clone, Copy)]
-pub enum TabTeam {
-    Home,
-    Visitor,
-}
-
-pub struct TabsState<'a> {
-    pub titles: [&'a str; TAB_NUM],
-    pub index: usize,
-    pub team: TabTeam,
-}
-
-impl<'a> TabsState<'a> {
-    pub fn new(titles: [&'a str; TAB_NUM]) -> TabsState {
-        TabsState {
-            titles,
-            index: 0,
-            team: TabTeam::Home,
-        }
-    }
-    pub fn next(&mut self) {
-        self.index = (self.index + 1) % TAB_NUM;
-    }
-
-    pub fn next_team(&mut self) {
-        self.team = match self.team {
-            TabTeam::Home => TabTeam::Visitor,
-            TabTeam::Visitor => TabTeam::Home,
-        }
-    }
-
-    pub fn previous(&mut self) {
-        if self.index > 0 {
-            self.index -= 1;
-        } else {
-            self.index = self.titles.len() - 1;
-        }
-    }
-}
-
-pub struct StatefulList<T> {
-    pub state: ListState,
-    pub items: Vec<T>,
-}
-
-impl<T> StatefulList<T> {
-    pub fn with_items(items: Vec<T>) -> StatefulList<T> {
-        StatefulList {
-            state: ListState::default(),
-            items,
-        }
-    }
-
-    pub fn next(&mut self) {
-        let i = match self.state.selected() {
-            Some(i) => {
-                if i >= self.items.len() - 1 {
-                    0
-                } else {
-                    i + 1
-                }
-            }
-            None => 0,
-        };
-        self.state.select(Some(i));
-    }
-
-    pub fn previous(&mut self) {
-        let i = match self.state.selected() {
-            Some(i) => {
-                if i == 0 {
-                    self.items.len() - 1
-                } else {
-                    i - 1
-                }
-            }
-            None => 0,
-        };
-        self.state.select(Some(i));
-    }
+    /// Rank by name.
+    pub name: usize,
+    /// Rank by position.
+    pub pos: usize,
+    /// Rank by points scored.
+    pub points: usize,
+    /// Rank by minutes played.
+    pub min: usize,
+    /// Rank by field goals made.
+    pub fgm: usize,
+    /// Rank by field goals attempted.
+    pub fga: usize,
+    /// Rank by field goal percentage.
+    pub fgp: usize,
+    /// Rank by free throws made.
+    pub ftm: usize,
+    /// Rank by free throws attempted.
+    pub fta: usize,
+    /// Rank by free throw percentage.
+    pub ftp: usize,
+    /// Rank by three pointers made.
+    pub tpm: usize,
+    /// Rank by three pointers attempted.
+    pub tpa: usize,
+    /// Rank by three point percentage.
+    pub tpp: usize,
+    /// Rank by offensive rebounds.
+    pub off_reb: usize,
+    /// Rank by defensive rebounds.
+    pub def_reb: usize,
+    /// Rank by total rebounds.
+    pub tot_reb: usize,
+    /// Rank by assists.
+    pub assists: usize,
+    /// Rank by personal fouls.
+    pub p_fouls: usize,
+    /// Rank by steals.
+    pub steals: usize,
+    /// Rank by turnovers.
+    pub turnovers: usize,
+    /// Rank by blocked shots.
+    pub blocks: usize,
+    /// Rank by point differential while on the court.
+    pub plus_minus: usize,
 }